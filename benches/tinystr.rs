@@ -73,11 +73,50 @@ fn construct_from_str(c: &mut Criterion) {
                 })
             }
         };
-    };
+    }
 
     bench_block!(c, "construct_from_str", cfs);
 }
 
+/// The masked `FromStr` path that `TinyStr8` and `TinyStr16` use, applied
+/// to `TinyStr4`, for comparison with its per-length `match`.
+#[inline(always)]
+fn parse_branchless_4(text: &str) -> Option<TinyStr4> {
+    let len = text.len();
+    if !(1..=4).contains(&len) {
+        return None;
+    }
+    let mut bytes = [0; 4];
+    bytes[..len].copy_from_slice(text.as_bytes());
+    let word = u32::from_le_bytes(bytes);
+    let mask = 0x8080_8080u32 >> (8 * (4 - len));
+    if word & mask != 0 || (mask - word) & mask != 0 {
+        return None;
+    }
+    Some(unsafe { TinyStr4::new_unchecked(word) })
+}
+
+fn construct_from_str_branchless(c: &mut Criterion) {
+    let funcs = vec![
+        Fun::new("unrolled", |b: &mut Bencher, strings: &Vec<&str>| {
+            b.iter(|| {
+                for s in strings {
+                    let _: TinyStr4 = black_box(s.parse().unwrap());
+                }
+            })
+        }),
+        Fun::new("branchless", |b: &mut Bencher, strings: &Vec<&str>| {
+            b.iter(|| {
+                for s in strings {
+                    let _ = black_box(parse_branchless_4(s).unwrap());
+                }
+            })
+        }),
+    ];
+
+    c.bench_functions("construct_from_str_branchless/4", funcs, STRINGS_4.to_vec());
+}
+
 fn construct_unchecked(c: &mut Criterion) {
     macro_rules! cu {
         ($tty:ty, $rty:ty) => {
@@ -93,7 +132,7 @@ fn construct_unchecked(c: &mut Criterion) {
                 })
             }
         };
-    };
+    }
 
     let funcs = vec![Fun::new("TinyStr4", cu!(TinyStr4, u32))];
 
@@ -108,6 +147,26 @@ fn construct_unchecked(c: &mut Criterion) {
     c.bench_functions("construct_unchecked/16", funcs, STRINGS_16.to_vec());
 }
 
+fn construct_parse_many(c: &mut Criterion) {
+    let funcs = vec![
+        Fun::new("from_str", |b: &mut Bencher, inputs: &Vec<&str>| {
+            b.iter(|| {
+                let out: Vec<Result<TinyStr16, _>> = inputs.iter().map(|s| s.parse()).collect();
+                black_box(out)
+            })
+        }),
+        Fun::new("parse_many", |b: &mut Bencher, inputs: &Vec<&str>| {
+            b.iter(|| {
+                let mut out = Vec::new();
+                TinyStr16::parse_many(inputs, &mut out);
+                black_box(out)
+            })
+        }),
+    ];
+
+    c.bench_functions("construct_parse_many/16", funcs, STRINGS_16.to_vec());
+}
+
 macro_rules! convert_to_ascii {
     ($ty:ty, $action:ident) => {
         |b: &mut Bencher, inputs: &Vec<&str>| {
@@ -142,11 +201,11 @@ fn convert_to_ascii_uppercase(c: &mut Criterion) {
 }
 
 trait ExtToAsciiTitlecase {
-    #[inline(always)]
     fn to_ascii_titlecase(&self) -> String;
 }
 
 impl ExtToAsciiTitlecase for str {
+    #[inline(always)]
     fn to_ascii_titlecase(&self) -> String {
         let mut result = self.to_ascii_lowercase();
         result[0..1].make_ascii_uppercase();
@@ -164,12 +223,41 @@ fn convert_to_ascii_titlecase(c: &mut Criterion) {
     bench_block!(c, "convert_to_ascii_titlecase", ctat);
 }
 
-trait ExtIsAsciiAlphanumeric {
+trait ExtToAsciiSwapcase {
+    fn to_ascii_swapcase(&self) -> String;
+}
+
+impl ExtToAsciiSwapcase for str {
     #[inline(always)]
+    fn to_ascii_swapcase(&self) -> String {
+        self.chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect()
+    }
+}
+
+fn convert_to_ascii_swapcase(c: &mut Criterion) {
+    macro_rules! ctas {
+        ($ty:ty) => {
+            convert_to_ascii!($ty, to_ascii_swapcase)
+        };
+    }
+
+    bench_block!(c, "convert_to_ascii_swapcase", ctas);
+}
+
+trait ExtIsAsciiAlphanumeric {
     fn is_ascii_alphanumeric(&self) -> bool;
 }
 
 impl ExtIsAsciiAlphanumeric for str {
+    #[inline(always)]
     fn is_ascii_alphanumeric(&self) -> bool {
         self.chars().all(|c| c.is_ascii_alphanumeric())
     }
@@ -211,14 +299,207 @@ fn test_eq(c: &mut Criterion) {
     bench_block!(c, "test_eq", te);
 }
 
+fn test_eq_ignore_ascii_case(c: &mut Criterion) {
+    macro_rules! teiac {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        for l in &raw {
+                            let _ = black_box(s.eq_ignore_ascii_case(l));
+                        }
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "test_eq_ignore_ascii_case", teiac);
+}
+
+fn test_starts_with(c: &mut Criterion) {
+    macro_rules! tsw {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        for p in &["U", "Ha", "mac", "Latn"] {
+                            let _ = black_box(s.starts_with(p));
+                        }
+                    }
+                })
+            }
+        };
+    }
+
+    macro_rules! tsw_deref {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        for p in &["U", "Ha", "mac", "Latn"] {
+                            let _ = black_box(s.as_str().starts_with(p));
+                        }
+                    }
+                })
+            }
+        };
+    }
+
+    let funcs = vec![
+        Fun::new("TinyStr4", tsw!(TinyStr4)),
+        Fun::new("TinyStr4/deref", tsw_deref!(TinyStr4)),
+        Fun::new("TinyStr8", tsw!(TinyStr8)),
+        Fun::new("TinyStr8/deref", tsw_deref!(TinyStr8)),
+        Fun::new("TinyStr16", tsw!(TinyStr16)),
+        Fun::new("TinyStr16/deref", tsw_deref!(TinyStr16)),
+    ];
+
+    c.bench_functions("test_starts_with/4", funcs, STRINGS_4.to_vec());
+}
+
+fn test_contains(c: &mut Criterion) {
+    macro_rules! tc {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        for p in &["S", "an", "ind", "Latn"] {
+                            let _ = black_box(s.contains(p));
+                        }
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "test_contains", tc);
+}
+
+fn test_find(c: &mut Criterion) {
+    macro_rules! tf {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        let _ = black_box(s.find('n'));
+                        let _ = black_box(s.rfind('n'));
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "test_find", tf);
+}
+
+trait ExtReplace {
+    fn replace(&self, from: char, to: char) -> String;
+}
+
+impl ExtReplace for String {
+    #[inline(always)]
+    fn replace(&self, from: char, to: char) -> String {
+        self.as_str().replace(from, to.encode_utf8(&mut [0; 4]))
+    }
+}
+
+fn convert_replace(c: &mut Criterion) {
+    macro_rules! cr {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        let _ = black_box(s.replace('n', '-'));
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "convert_replace", cr);
+}
+
+trait ExtCountChar {
+    fn count_char(&self, c: char) -> usize;
+}
+
+impl ExtCountChar for String {
+    #[inline(always)]
+    fn count_char(&self, c: char) -> usize {
+        self.chars().filter(|&x| x == c).count()
+    }
+}
+
+fn test_count_char(c: &mut Criterion) {
+    macro_rules! tcc {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        let _ = black_box(s.count_char('n'));
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "test_count_char", tcc);
+}
+
+trait ExtToU64 {
+    fn to_u64(&self) -> Option<u64>;
+}
+
+impl ExtToU64 for String {
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        self.parse().ok()
+    }
+}
+
+fn convert_to_u64(c: &mut Criterion) {
+    macro_rules! ctu {
+        ($ty:ty) => {
+            |b: &mut Bencher, inputs: &Vec<&str>| {
+                let raw: Vec<$ty> = inputs.iter().map(|s| s.parse::<$ty>().unwrap()).collect();
+                b.iter(move || {
+                    for s in &raw {
+                        let _ = black_box(s.to_u64());
+                    }
+                })
+            }
+        };
+    }
+
+    bench_block!(c, "convert_to_u64", ctu);
+}
+
 criterion_group!(
     benches,
     construct_from_str,
+    construct_from_str_branchless,
     construct_unchecked,
+    construct_parse_many,
     convert_to_ascii_lowercase,
     convert_to_ascii_uppercase,
     convert_to_ascii_titlecase,
+    convert_to_ascii_swapcase,
     test_is_ascii_alphanumeric,
     test_eq,
+    test_eq_ignore_ascii_case,
+    test_starts_with,
+    test_contains,
+    test_find,
+    convert_replace,
+    test_count_char,
+    convert_to_u64,
 );
 criterion_main!(benches);
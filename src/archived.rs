@@ -0,0 +1,234 @@
+//! `rkyv` support: the archived forms of `TinyStr4`, `TinyStr8` and
+//! `TinyStr16`.
+
+use std::fmt;
+
+use rkyv::{Archive, Archived, CheckBytes, Deserialize, Fallible, Serialize};
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// The error returned by `check_archived_root` when an archived `TinyStr*`
+/// doesn't hold a valid string.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArchivedTinyStrError(pub Error);
+
+impl fmt::Display for ArchivedTinyStrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid archived TinyStr: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ArchivedTinyStrError {}
+
+/// A `TinyStr4` as stored in an `rkyv` archive: its little-endian `u32` word.
+///
+/// `check_archived_root` rejects words that aren't a valid `TinyStr4`, so an
+/// `ArchivedTinyStr4` reached through it, or through `archived_root` on bytes
+/// known to be valid, always holds one.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArchivedTinyStr4(Archived<u32>);
+
+impl ArchivedTinyStr4 {
+    /// Returns the archived string.
+    #[inline]
+    pub fn get(&self) -> TinyStr4 {
+        // Valid by the invariant on `ArchivedTinyStr4`.
+        unsafe { TinyStr4::new_unchecked(self.0.value()) }
+    }
+}
+
+impl fmt::Debug for ArchivedTinyStr4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl PartialEq<TinyStr4> for ArchivedTinyStr4 {
+    #[inline]
+    fn eq(&self, other: &TinyStr4) -> bool {
+        self.0.value() == u32::from(*other)
+    }
+}
+
+impl<C: ?Sized> CheckBytes<C> for ArchivedTinyStr4 {
+    type Error = ArchivedTinyStrError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        _context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let word = (*value).0.value();
+        TinyStr4::from_bytes_padded(word.to_le_bytes()).map_err(ArchivedTinyStrError)?;
+        Ok(&*value)
+    }
+}
+
+/// Archives as the little-endian `u32` word, which is the string bytes
+/// followed by NUL padding whatever the platform.
+impl Archive for TinyStr4 {
+    type Archived = ArchivedTinyStr4;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: (), out: *mut ArchivedTinyStr4) {
+        // `ArchivedTinyStr4` is a transparent `Archived<u32>`.
+        u32::from(*self).resolve(pos, resolver, out.cast());
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for TinyStr4 {
+    #[inline]
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<TinyStr4, D> for ArchivedTinyStr4 {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<TinyStr4, D::Error> {
+        Ok(self.get())
+    }
+}
+
+/// A `TinyStr8` as stored in an `rkyv` archive: its little-endian `u64` word.
+///
+/// `check_archived_root` rejects words that aren't a valid `TinyStr8`, so an
+/// `ArchivedTinyStr8` reached through it, or through `archived_root` on bytes
+/// known to be valid, always holds one.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArchivedTinyStr8(Archived<u64>);
+
+impl ArchivedTinyStr8 {
+    /// Returns the archived string.
+    #[inline]
+    pub fn get(&self) -> TinyStr8 {
+        // Valid by the invariant on `ArchivedTinyStr8`.
+        unsafe { TinyStr8::new_unchecked(self.0.value()) }
+    }
+}
+
+impl fmt::Debug for ArchivedTinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl PartialEq<TinyStr8> for ArchivedTinyStr8 {
+    #[inline]
+    fn eq(&self, other: &TinyStr8) -> bool {
+        self.0.value() == u64::from(*other)
+    }
+}
+
+impl<C: ?Sized> CheckBytes<C> for ArchivedTinyStr8 {
+    type Error = ArchivedTinyStrError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        _context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let word = (*value).0.value();
+        TinyStr8::from_bytes_padded(word.to_le_bytes()).map_err(ArchivedTinyStrError)?;
+        Ok(&*value)
+    }
+}
+
+/// Archives as the little-endian `u64` word, which is the string bytes
+/// followed by NUL padding whatever the platform.
+impl Archive for TinyStr8 {
+    type Archived = ArchivedTinyStr8;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: (), out: *mut ArchivedTinyStr8) {
+        // `ArchivedTinyStr8` is a transparent `Archived<u64>`.
+        u64::from(*self).resolve(pos, resolver, out.cast());
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for TinyStr8 {
+    #[inline]
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<TinyStr8, D> for ArchivedTinyStr8 {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<TinyStr8, D::Error> {
+        Ok(self.get())
+    }
+}
+
+/// A `TinyStr16` as stored in an `rkyv` archive: its little-endian `u128` word.
+///
+/// `check_archived_root` rejects words that aren't a valid `TinyStr16`, so an
+/// `ArchivedTinyStr16` reached through it, or through `archived_root` on bytes
+/// known to be valid, always holds one.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArchivedTinyStr16(Archived<u128>);
+
+impl ArchivedTinyStr16 {
+    /// Returns the archived string.
+    #[inline]
+    pub fn get(&self) -> TinyStr16 {
+        // Valid by the invariant on `ArchivedTinyStr16`.
+        unsafe { TinyStr16::new_unchecked(self.0.value()) }
+    }
+}
+
+impl fmt::Debug for ArchivedTinyStr16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl PartialEq<TinyStr16> for ArchivedTinyStr16 {
+    #[inline]
+    fn eq(&self, other: &TinyStr16) -> bool {
+        self.0.value() == u128::from(*other)
+    }
+}
+
+impl<C: ?Sized> CheckBytes<C> for ArchivedTinyStr16 {
+    type Error = ArchivedTinyStrError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        _context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let word = (*value).0.value();
+        TinyStr16::from_bytes_padded(word.to_le_bytes()).map_err(ArchivedTinyStrError)?;
+        Ok(&*value)
+    }
+}
+
+/// Archives as the little-endian `u128` word, which is the string bytes
+/// followed by NUL padding whatever the platform.
+impl Archive for TinyStr16 {
+    type Archived = ArchivedTinyStr16;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: (), out: *mut ArchivedTinyStr16) {
+        // `ArchivedTinyStr16` is a transparent `Archived<u128>`.
+        u128::from(*self).resolve(pos, resolver, out.cast());
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for TinyStr16 {
+    #[inline]
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<TinyStr16, D> for ArchivedTinyStr16 {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<TinyStr16, D::Error> {
+        Ok(self.get())
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+use std::ops::Deref;
+
+/// A `TinyStr4`, `TinyStr8` or `TinyStr16` that compares, orders and
+/// hashes ignoring ASCII case, so `"GZIP"` and `"gzip"` are the same key.
+///
+/// `Deref` and `Display` still see the original spelling.
+#[derive(Copy, Clone, Debug)]
+pub struct CaseInsensitive<T>(T);
+
+impl<T> CaseInsensitive<T> {
+    /// Wraps `inner`.
+    #[inline(always)]
+    pub const fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the wrapped string, with its original spelling.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for CaseInsensitive<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CaseInsensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::num::NonZeroU32;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Not, Shl, Shr, Sub};
 use std::ptr::copy_nonoverlapping;
 
 use super::Error;
@@ -13,11 +15,315 @@ pub(crate) unsafe fn make_4byte_str(
     let mask = u32::from_le(mask);
     let mut word: u32 = 0;
     copy_nonoverlapping(text.as_ptr(), &mut word as *mut u32 as *mut u8, len);
-    if (word & mask) != 0 {
-        return Err(Error::NonAscii);
+    let high = word & mask;
+    if high != 0 {
+        return Err(Error::NonAscii {
+            index: (high.to_le().trailing_zeros() / 8) as usize,
+        });
     }
-    if ((mask - word) & mask) != 0 {
-        return Err(Error::InvalidNull);
+    let nulls = (mask - word) & mask;
+    if nulls != 0 {
+        return Err(Error::InvalidNull {
+            index: (nulls.to_le().trailing_zeros() / 8) as usize,
+        });
     }
     Ok(NonZeroU32::new_unchecked(word))
 }
+
+/// The integer types backing `TinyStr4`, `TinyStr8` and `TinyStr16`.
+///
+/// The SWAR algorithms below only look at each byte lane in isolation, so
+/// they're written once against this trait and work on words in either
+/// byte order.
+pub(crate) trait Word:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + Sub<Output = Self>
+{
+    const ZERO: Self;
+
+    /// Returns a word with every byte lane set to `byte`.
+    fn splat(byte: u8) -> Self;
+
+    fn to_be(self) -> Self;
+
+    fn count_ones(self) -> u32;
+
+    fn trailing_zeros(self) -> u32;
+
+    fn leading_zeros(self) -> u32;
+}
+
+macro_rules! impl_word {
+    ($t:ty) => {
+        impl Word for $t {
+            const ZERO: Self = 0;
+
+            #[inline(always)]
+            fn splat(byte: u8) -> Self {
+                <$t>::from_ne_bytes([byte; std::mem::size_of::<$t>()])
+            }
+
+            #[inline(always)]
+            fn to_be(self) -> Self {
+                <$t>::to_be(self)
+            }
+
+            #[inline(always)]
+            fn count_ones(self) -> u32 {
+                <$t>::count_ones(self)
+            }
+
+            #[inline(always)]
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+
+            #[inline(always)]
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
+        }
+    };
+}
+
+impl_word!(u32);
+impl_word!(u64);
+impl_word!(u128);
+
+/// Returns a word with the high bit set in every non-NUL lane.
+#[inline(always)]
+fn content_lanes<W: Word>(word: W) -> W {
+    (word + W::splat(0x7f)) & W::splat(0x80)
+}
+
+/// Returns a word with the high bit set in every uppercase letter lane.
+#[inline(always)]
+fn upper_lanes<W: Word>(word: W) -> W {
+    (word + W::splat(0x3f)) & !(word + W::splat(0x25)) & W::splat(0x80)
+}
+
+/// Returns a word with the high bit set in every lowercase letter lane.
+#[inline(always)]
+fn lower_lanes<W: Word>(word: W) -> W {
+    (word + W::splat(0x1f)) & !(word + W::splat(0x05)) & W::splat(0x80)
+}
+
+/// Returns a word with the high bit set in every lane that isn't a letter.
+#[inline(always)]
+fn non_alpha_lanes<W: Word>(word: W) -> W {
+    let lower = word | W::splat(0x20);
+    !(lower + W::splat(0x1f)) | (lower + W::splat(0x05))
+}
+
+/// Returns a word with the high bit set in every lane that isn't a digit.
+#[inline(always)]
+fn non_numeric_lanes<W: Word>(word: W) -> W {
+    !(word + W::splat(0x50)) | (word + W::splat(0x46))
+}
+
+#[inline(always)]
+pub(crate) fn to_ascii_uppercase<W: Word>(word: W) -> W {
+    word & !(lower_lanes(word) >> 2)
+}
+
+#[inline(always)]
+pub(crate) fn to_ascii_lowercase<W: Word>(word: W) -> W {
+    word | (upper_lanes(word) >> 2)
+}
+
+#[inline(always)]
+pub(crate) fn to_ascii_swapcase<W: Word>(word: W) -> W {
+    word ^ (lower_lanes(word | W::splat(0x20)) >> 2)
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_uppercase<W: Word>(word: W) -> bool {
+    lower_lanes(word) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_lowercase<W: Word>(word: W) -> bool {
+    upper_lanes(word) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_alphanumeric<W: Word>(word: W) -> bool {
+    (non_alpha_lanes(word) & non_numeric_lanes(word) & content_lanes(word)) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_alphabetic<W: Word>(word: W) -> bool {
+    (non_alpha_lanes(word) & content_lanes(word)) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_numeric<W: Word>(word: W) -> bool {
+    (non_numeric_lanes(word) & content_lanes(word)) == W::ZERO
+}
+
+/// Returns `true` if every non-NUL lane is an ASCII letter, digit or `_`.
+#[inline(always)]
+pub(crate) fn is_ascii_word<W: Word>(word: W) -> bool {
+    let non_word = non_alpha_lanes(word) & non_numeric_lanes(word) & !lanes_eq(word, b'_');
+    (non_word & content_lanes(word)) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn is_ascii_hexdigit<W: Word>(word: W) -> bool {
+    let lower = word | W::splat(0x20);
+    let non_hex = !(lower + W::splat(0x1f)) | (lower + W::splat(0x19));
+    (non_numeric_lanes(word) & non_hex & content_lanes(word)) == W::ZERO
+}
+
+#[inline(always)]
+pub(crate) fn count_uppercase<W: Word>(word: W) -> usize {
+    upper_lanes(word).count_ones() as usize
+}
+
+#[inline(always)]
+pub(crate) fn count_lowercase<W: Word>(word: W) -> usize {
+    lower_lanes(word).count_ones() as usize
+}
+
+#[inline(always)]
+pub(crate) fn count_digits<W: Word>(word: W) -> usize {
+    (content_lanes(word) & !non_numeric_lanes(word)).count_ones() as usize
+}
+
+#[inline(always)]
+pub(crate) fn count_alphabetic<W: Word>(word: W) -> usize {
+    (content_lanes(word) & !non_alpha_lanes(word)).count_ones() as usize
+}
+
+/// Returns a word with the high bit set in every lane equal to `byte`.
+#[inline(always)]
+pub(crate) fn lanes_eq<W: Word>(word: W, byte: u8) -> W {
+    let x = word ^ W::splat(byte);
+    !(((x & W::splat(0x7f)) + W::splat(0x7f)) | x) & W::splat(0x80)
+}
+
+/// Lowercases `word`, then uppercases the lanes with the high bit set in
+/// `starts`.
+#[inline(always)]
+pub(crate) fn titlecase_lanes<W: Word>(word: W, starts: W) -> W {
+    let lower = to_ascii_lowercase(word);
+    lower & !(((lower + W::splat(0x1f)) & !(lower + W::splat(0x05)) & starts) >> 2)
+}
+
+/// Returns the first `len` bytes of `word` in memory order.
+///
+/// # Safety
+///
+/// `len` must not exceed the size of `W`.
+#[inline(always)]
+pub(crate) unsafe fn word_as_bytes<W>(word: &W, len: usize) -> &[u8] {
+    std::slice::from_raw_parts(word as *const W as *const u8, len)
+}
+
+/// Compares two words as the strings they hold, lexicographically by byte.
+#[inline(always)]
+pub(crate) fn cmp<W: Word>(a: W, b: W) -> Ordering {
+    a.to_be().cmp(&b.to_be())
+}
+
+/// Returns a word with the high bit set in every non-NUL lane that isn't
+/// whitespace as `char::is_whitespace` sees it: tab, line feed, vertical
+/// tab, form feed, carriage return and space.
+#[inline(always)]
+pub(crate) fn non_whitespace_lanes<W: Word>(word: W) -> W {
+    let controls = (word + W::splat(0x77)) & !(word + W::splat(0x72));
+    non_space_lanes(word) & !controls
+}
+
+/// Returns a word with the high bit set in every ASCII control lane: bytes
+/// below 0x20 other than NUL, and DEL.
+#[inline(always)]
+pub(crate) fn control_lanes<W: Word>(word: W) -> W {
+    let graphic = (word + W::splat(0x60)) & !(word + W::splat(0x01));
+    content_lanes(word) & !graphic
+}
+
+/// Returns a word with the high bit set in every non-ASCII lane.
+#[inline(always)]
+pub(crate) fn non_ascii_lanes<W: Word>(word: W) -> W {
+    word & W::splat(0x80)
+}
+
+/// Returns a word with the high bit set in every non-NUL lane that isn't a
+/// space (0x20).
+#[inline(always)]
+pub(crate) fn non_space_lanes<W: Word>(word: W) -> W {
+    content_lanes(word) & !lanes_eq(word, b' ')
+}
+
+/// Returns a little-endian word whose first `count` lanes hold `byte` and
+/// whose other lanes are NUL.
+#[inline(always)]
+pub(crate) fn repeat_lanes<W: Word>(byte: u8, count: usize) -> W {
+    W::splat(byte) >> (8 * (std::mem::size_of::<W>() - count)) as u32
+}
+
+/// Returns a word with the high bit set in each of the first `len` lanes of
+/// a little-endian word.
+#[inline(always)]
+pub(crate) fn prefix_lanes<W: Word>(len: usize) -> W {
+    repeat_lanes(0x80, len)
+}
+
+/// Returns a word with the high bit set in each NUL lane among the first
+/// `len` lanes of the little-endian ASCII word `word`.
+#[inline(always)]
+pub(crate) fn nul_lanes<W: Word>(word: W, len: usize) -> W {
+    let mask: W = prefix_lanes(len);
+    (mask - word) & mask
+}
+
+/// Replaces every lane with the high bit set in `lanes` with `byte`.
+#[inline(always)]
+pub(crate) fn replace_lanes<W: Word>(word: W, lanes: W, byte: u8) -> W {
+    let fill = lanes | (lanes - (lanes >> 7));
+    (word & !fill) | (W::splat(byte) & fill)
+}
+
+/// Returns the index of the first lane with the high bit set in the
+/// little-endian word `lanes`, which must not be zero.
+#[inline(always)]
+pub(crate) fn first_lane<W: Word>(lanes: W) -> usize {
+    (lanes.trailing_zeros() / 8) as usize
+}
+
+/// Returns the index of the last lane with the high bit set in the
+/// little-endian word `lanes`, which must not be zero.
+#[inline(always)]
+pub(crate) fn last_lane<W: Word>(lanes: W) -> usize {
+    std::mem::size_of::<W>() - 1 - (lanes.leading_zeros() / 8) as usize
+}
+
+/// Drops the lanes of the little-endian word `word` before the first lane
+/// with the high bit set in `keep`, or returns `None` if there is none.
+#[inline(always)]
+pub(crate) fn trim_start_lanes<W: Word>(word: W, keep: W) -> Option<W> {
+    if keep == W::ZERO {
+        return None;
+    }
+    Some(word >> (keep.trailing_zeros() & !7))
+}
+
+/// Clears the lanes of the little-endian word `word` after the last lane
+/// with the high bit set in `keep`, or returns `None` if there is none.
+#[inline(always)]
+pub(crate) fn trim_end_lanes<W: Word>(word: W, keep: W) -> Option<W> {
+    if keep == W::ZERO {
+        return None;
+    }
+    let shift = keep.leading_zeros() & !7;
+    Some((word << shift) >> shift)
+}
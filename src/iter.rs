@@ -0,0 +1,127 @@
+use std::iter::FusedIterator;
+
+/// An iterator over the bytes of a `TinyStr4`, `TinyStr8` or `TinyStr16`.
+///
+/// Created by the `bytes` method on each of those types.
+#[derive(Clone, Debug)]
+pub struct Bytes {
+    // The string bytes in little-endian order.
+    word: u128,
+    front: usize,
+    back: usize,
+}
+
+impl Bytes {
+    #[inline(always)]
+    pub(crate) fn new(word: u128, len: usize) -> Self {
+        Self {
+            word,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+impl Iterator for Bytes {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+        let byte = (self.word >> (8 * self.front)) as u8;
+        self.front += 1;
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Bytes {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some((self.word >> (8 * self.back)) as u8)
+    }
+}
+
+impl ExactSizeIterator for Bytes {}
+
+impl FusedIterator for Bytes {}
+
+/// An iterator over the chars of a `TinyStr4`, `TinyStr8` or `TinyStr16`.
+///
+/// Since the content is always ASCII, every byte is a `char` and, unlike
+/// `std::str::Chars`, the iterator knows its exact length.
+///
+/// Created by the `chars` method on each of those types.
+#[derive(Clone, Debug)]
+pub struct Chars(Bytes);
+
+impl Chars {
+    #[inline(always)]
+    pub(crate) fn new(word: u128, len: usize) -> Self {
+        Self(Bytes::new(word, len))
+    }
+}
+
+impl Iterator for Chars {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.0.next().map(char::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Chars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back().map(char::from)
+    }
+}
+
+impl ExactSizeIterator for Chars {}
+
+impl FusedIterator for Chars {}
+
+/// An iterator over the non-empty segments of a `TinyStr4`, `TinyStr8` or
+/// `TinyStr16` separated by a char, yielding values of the same type.
+///
+/// Created by the `split` method on each of those types.
+#[derive(Clone, Debug)]
+pub struct Split<T> {
+    rest: Option<T>,
+    sep: char,
+}
+
+impl<T> Split<T> {
+    #[inline(always)]
+    pub(crate) fn new(s: T, sep: char) -> Self {
+        Self { rest: Some(s), sep }
+    }
+
+    /// Returns the unsplit remainder, replacing it with `rest`.
+    #[inline(always)]
+    pub(crate) fn advance(&mut self, rest: Option<T>) -> Option<T> {
+        std::mem::replace(&mut self.rest, rest)
+    }
+
+    #[inline(always)]
+    pub(crate) fn sep(&self) -> char {
+        self.sep
+    }
+}
@@ -13,7 +13,7 @@
 //!     assert_eq!(s1.to_ascii_uppercase(), "TEST");
 //!     assert_eq!(s1.to_ascii_lowercase(), "test");
 //!     assert_eq!(s1.to_ascii_titlecase(), "Test");
-//!     assert_eq!(s1.is_ascii_alphanumeric(), true);
+//!     assert!(s1.is_ascii_alphanumeric());
 //!
 //!     let s2: TinyStr8 = "New York".parse()
 //!         .expect("Failed to parse.");
@@ -21,21 +21,149 @@
 //!     assert_eq!(s2, "New York");
 //!     assert_eq!(s2.to_ascii_uppercase(), "NEW YORK");
 //!     assert_eq!(s2.to_ascii_lowercase(), "new york");
-//!     assert_eq!(s2.is_ascii_alphanumeric(), false);
+//!     assert!(!s2.is_ascii_alphanumeric());
 //! }
 //! ```
+#[cfg(feature = "rkyv")]
+mod archived;
+mod case_insensitive;
 mod helpers;
+mod iter;
+#[cfg(feature = "nom")]
+mod parsers;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod subtag;
 mod tinystr16;
 mod tinystr4;
 mod tinystr8;
+mod tinystrbuf16;
+mod tinystrbuf4;
+mod tinystrbuf8;
+#[cfg(feature = "zerovec")]
+mod ule;
+#[cfg(feature = "zerocopy")]
+mod unvalidated;
 
+#[cfg(feature = "rkyv")]
+pub use archived::{ArchivedTinyStr16, ArchivedTinyStr4, ArchivedTinyStr8, ArchivedTinyStrError};
+pub use case_insensitive::CaseInsensitive;
+pub use iter::{Bytes, Chars, Split};
+#[cfg(feature = "nom")]
+pub use parsers::{
+    parse_tinystr16, parse_tinystr16_exact, parse_tinystr4, parse_tinystr4_exact, parse_tinystr8,
+    parse_tinystr8_exact,
+};
+#[cfg(feature = "proptest")]
+pub use strategy::{any_tinystr16, any_tinystr4, any_tinystr8};
+pub use subtag::SubtagKind;
 pub use tinystr16::TinyStr16;
 pub use tinystr4::TinyStr4;
 pub use tinystr8::TinyStr8;
+pub use tinystrbuf16::{TinyStr16Builder, TinyStr16Collector, TinyStrBuf16};
+pub use tinystrbuf4::{TinyStr4Builder, TinyStr4Collector, TinyStrBuf4};
+pub use tinystrbuf8::{TinyStr8Builder, TinyStr8Collector, TinyStrBuf8};
+#[cfg(feature = "zerovec")]
+pub use ule::{TinyStr16ULE, TinyStr4ULE, TinyStr8ULE};
+#[cfg(feature = "zerocopy")]
+pub use unvalidated::{TinyStr16Unvalidated, TinyStr4Unvalidated, TinyStr8Unvalidated};
+
+use std::str::FromStr;
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum Error {
-    InvalidSize,
-    InvalidNull,
-    NonAscii,
+    /// The string is `len` bytes long, which is not in `1..=max`.
+    InvalidSize { len: usize, max: usize },
+    /// The string contains a NUL byte at `index`.
+    InvalidNull { index: usize },
+    /// The string contains a non-ASCII byte at `index`.
+    NonAscii { index: usize },
+    /// The string contains an ASCII control character at `index`.
+    NonGraphic { index: usize },
+    /// The byte at `index` was rejected by a custom check.
+    FailedValidation { index: usize },
+    /// Parsing the string at `index` of a batch failed with `inner`.
+    BatchError { index: usize, inner: Box<Error> },
+}
+
+/// Formats the arguments into the given tinystr type without allocating.
+///
+/// Expands to a call to that type's `from_fmt`, returning a
+/// `Result<$ty, Error>` that fails rather than truncating if the output
+/// doesn't fit or contains non-ASCII or NUL characters.
+///
+/// # Example
+///
+/// ```
+/// use tinystr::{format_tiny, TinyStr8};
+///
+/// let s = format_tiny!(TinyStr8, "{}-{:03}", "x", 7).unwrap();
+/// assert_eq!(s, "x-007");
+/// assert!(format_tiny!(TinyStr8, "{:>9}", "x").is_err());
+/// ```
+#[macro_export]
+macro_rules! format_tiny {
+    ($ty:ty, $($arg:tt)*) => {
+        <$ty>::from_fmt(::std::format_args!($($arg)*))
+    };
+}
+
+/// Parses every string in `strings`, stopping at the first failure.
+///
+/// The returned `Error::BatchError` carries the index of the offending
+/// string along with the error it produced.
+///
+/// # Example
+///
+/// ```
+/// use tinystr::{parse_many, Error, TinyStr4};
+///
+/// let langs: Vec<TinyStr4> = parse_many(&["en", "fr", "de"]).unwrap();
+/// assert_eq!(langs, vec!["en", "fr", "de"]);
+///
+/// assert_eq!(
+///     parse_many::<TinyStr4>(&["en", "english"]),
+///     Err(Error::BatchError {
+///         index: 1,
+///         inner: Box::new(Error::InvalidSize { len: 7, max: 4 })
+///     })
+/// );
+/// ```
+pub fn parse_many<T: FromStr<Err = Error>>(strings: &[&str]) -> Result<Vec<T>, Error> {
+    strings
+        .iter()
+        .enumerate()
+        .map(|(index, s)| {
+            s.parse().map_err(|inner| Error::BatchError {
+                index,
+                inner: Box::new(inner),
+            })
+        })
+        .collect()
+}
+
+/// Joins two `TinyStr4`s into a `TinyStr8` by shifting `b` above `a`.
+///
+/// Two `TinyStr4`s always fit in a `TinyStr8`, so this never fails today;
+/// it returns a `Result` to match `concat8` and the other fallible
+/// constructors.
+///
+/// # Example
+///
+/// ```
+/// use tinystr::{concat4, TinyStr4};
+///
+/// let zh: TinyStr4 = "zh".parse().unwrap();
+/// let hant: TinyStr4 = "Hant".parse().unwrap();
+/// assert_eq!(concat4(zh, hant).unwrap(), "zhHant");
+/// ```
+pub fn concat4(a: TinyStr4, b: TinyStr4) -> Result<TinyStr8, Error> {
+    Ok(a.concat(b))
+}
+
+/// Joins two `TinyStr8`s into a `TinyStr16` by shifting `b` above `a`.
+///
+/// Like `concat4`, this can't currently fail.
+pub fn concat8(a: TinyStr8, b: TinyStr8) -> Result<TinyStr16, Error> {
+    Ok(a.concat(b))
 }
@@ -0,0 +1,70 @@
+//! `nom` parsers for `TinyStr4`, `TinyStr8` and `TinyStr16`.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::{take, take_while_m_n};
+use nom::combinator::map_res;
+use nom::IResult;
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+#[inline(always)]
+fn is_tiny_byte(b: u8) -> bool {
+    b != 0 && b.is_ascii()
+}
+
+fn from_ascii<T: FromStr<Err = Error>>(bytes: &[u8]) -> Result<T, Error> {
+    std::str::from_utf8(bytes)
+        .map_err(|e| Error::NonAscii {
+            index: e.valid_up_to(),
+        })?
+        .parse()
+}
+
+/// Parses 1 to 4 ASCII bytes into a `TinyStr4`, stopping early at a NUL or
+/// non-ASCII byte.
+///
+/// Fails if the input doesn't start with such a byte.
+pub fn parse_tinystr4(input: &[u8]) -> IResult<&[u8], TinyStr4> {
+    map_res(take_while_m_n(1, 4, is_tiny_byte), from_ascii)(input)
+}
+
+/// Parses 1 to 8 ASCII bytes into a `TinyStr8`, stopping early at a NUL or
+/// non-ASCII byte.
+///
+/// Fails if the input doesn't start with such a byte.
+pub fn parse_tinystr8(input: &[u8]) -> IResult<&[u8], TinyStr8> {
+    map_res(take_while_m_n(1, 8, is_tiny_byte), from_ascii)(input)
+}
+
+/// Parses 1 to 16 ASCII bytes into a `TinyStr16`, stopping early at a NUL
+/// or non-ASCII byte.
+///
+/// Fails if the input doesn't start with such a byte.
+pub fn parse_tinystr16(input: &[u8]) -> IResult<&[u8], TinyStr16> {
+    map_res(take_while_m_n(1, 16, is_tiny_byte), from_ascii)(input)
+}
+
+/// Returns a parser consuming exactly `n` bytes into a `TinyStr4`.
+///
+/// The parser fails if fewer than `n` bytes remain, or if they don't form a
+/// valid `TinyStr4`, which is always the case for `n` outside `1..=4`.
+pub fn parse_tinystr4_exact(n: usize) -> impl Fn(&[u8]) -> IResult<&[u8], TinyStr4> {
+    move |input| map_res(take(n), from_ascii)(input)
+}
+
+/// Returns a parser consuming exactly `n` bytes into a `TinyStr8`.
+///
+/// The parser fails if fewer than `n` bytes remain, or if they don't form a
+/// valid `TinyStr8`, which is always the case for `n` outside `1..=8`.
+pub fn parse_tinystr8_exact(n: usize) -> impl Fn(&[u8]) -> IResult<&[u8], TinyStr8> {
+    move |input| map_res(take(n), from_ascii)(input)
+}
+
+/// Returns a parser consuming exactly `n` bytes into a `TinyStr16`.
+///
+/// The parser fails if fewer than `n` bytes remain, or if they don't form a
+/// valid `TinyStr16`, which is always the case for `n` outside `1..=16`.
+pub fn parse_tinystr16_exact(n: usize) -> impl Fn(&[u8]) -> IResult<&[u8], TinyStr16> {
+    move |input| map_res(take(n), from_ascii)(input)
+}
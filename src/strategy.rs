@@ -0,0 +1,32 @@
+//! `proptest` strategies for `TinyStr4`, `TinyStr8` and `TinyStr16`.
+
+use proptest::collection::vec;
+use proptest::strategy::Strategy;
+
+use crate::{TinyStr16, TinyStr4, TinyStr8};
+
+/// Returns a strategy for strings of 1 to `max` ASCII bytes in `1..=127`.
+fn ascii_strings(max: usize) -> impl Strategy<Value = String> {
+    vec(1u8..=127, 1..=max).prop_map(|bytes| String::from_utf8(bytes).unwrap())
+}
+
+/// Returns a strategy generating every valid `TinyStr4`.
+///
+/// Values shrink towards shorter strings of lower bytes.
+pub fn any_tinystr4() -> impl Strategy<Value = TinyStr4> {
+    ascii_strings(4).prop_map(|s| s.parse().unwrap())
+}
+
+/// Returns a strategy generating every valid `TinyStr8`.
+///
+/// Values shrink towards shorter strings of lower bytes.
+pub fn any_tinystr8() -> impl Strategy<Value = TinyStr8> {
+    ascii_strings(8).prop_map(|s| s.parse().unwrap())
+}
+
+/// Returns a strategy generating every valid `TinyStr16`.
+///
+/// Values shrink towards shorter strings of lower bytes.
+pub fn any_tinystr16() -> impl Strategy<Value = TinyStr16> {
+    ascii_strings(16).prop_map(|s| s.parse().unwrap())
+}
@@ -0,0 +1,12 @@
+/// The kind of a BCP-47 subtag, which decides its canonical case.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SubtagKind {
+    /// A language subtag like `"en"`, canonically lowercase.
+    Language,
+    /// A script subtag like `"Hant"`, canonically titlecase.
+    Script,
+    /// A region subtag like `"US"` or `"419"`, canonically uppercase.
+    Region,
+    /// A variant subtag like `"posix"`, canonically lowercase.
+    Variant,
+}
@@ -1,18 +1,45 @@
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
-use std::convert::Into;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
 use std::num::NonZeroU64;
-use std::ops::Deref;
+use std::ops::{Add, Deref};
 use std::ptr::copy_nonoverlapping;
 use std::str::FromStr;
 
+use crate::helpers::{self, lanes_eq, titlecase_lanes};
+use crate::Bytes;
+use crate::CaseInsensitive;
+use crate::Chars;
 use crate::Error;
+use crate::Split;
+use crate::SubtagKind;
+use crate::TinyStr16;
+use crate::TinyStr4;
+use crate::TinyStr8Collector;
+use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
 
 /// A tiny string that is from 1 to 8 non-NUL ASCII characters.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+///
+/// `Option<TinyStr8>` is guaranteed to be the same size as `TinyStr8`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::AsBytes))]
+#[repr(transparent)]
 pub struct TinyStr8(NonZeroU64);
 
+const _: () = assert!(std::mem::size_of::<Option<TinyStr8>>() == 8);
+
 impl TinyStr8 {
+    /// The maximum number of characters a `TinyStr8` can hold.
+    pub const CAPACITY: usize = 8;
+
+    /// Creates a `TinyStr8` from its raw little-endian `u64` representation.
+    ///
+    /// # Safety
+    ///
+    /// `text` must be non-zero and hold 1 to 8 ASCII characters
+    /// followed only by NUL padding, as produced by `Into<u64>`.
     #[inline(always)]
     pub const unsafe fn new_unchecked(text: u64) -> Self {
         Self(NonZeroU64::new_unchecked(u64::from_le(text)))
@@ -24,45 +51,1223 @@ impl TinyStr8 {
     }
 
     pub fn to_ascii_uppercase(self) -> Self {
-        let word = self.0.get();
-        let result = word
-            & !(((word + 0x1f1f1f1f_1f1f1f1f)
-                & !(word + 0x05050505_05050505)
-                & 0x80808080_80808080)
-                >> 2);
+        let result = helpers::to_ascii_uppercase(self.0.get());
         unsafe { Self(NonZeroU64::new_unchecked(result)) }
     }
 
     pub fn to_ascii_lowercase(self) -> Self {
-        let word = self.0.get();
-        let result = word
-            | (((word + 0x3f3f3f3f_3f3f3f3f)
-                & !(word + 0x25252525_25252525)
-                & 0x80808080_80808080)
-                >> 2);
+        let result = helpers::to_ascii_lowercase(self.0.get());
         unsafe { Self(NonZeroU64::new_unchecked(result)) }
     }
 
     pub fn is_ascii_alphanumeric(self) -> bool {
-        let word = self.0.get();
-        let mask = (word + 0x7f7f7f7f_7f7f7f7f) & 0x80808080_80808080;
-        let lower = word | 0x20202020_20202020;
-        ((!(lower + 0x1f1f1f1f_1f1f1f1f) | (lower + 0x05050505_05050505)) & mask) == 0
+        helpers::is_ascii_alphanumeric(self.0.get())
+    }
+
+    pub fn is_ascii_alphabetic(self) -> bool {
+        helpers::is_ascii_alphabetic(self.0.get())
     }
 
     pub fn to_ascii_titlecase(self) -> Self {
         let word = self.0.get().to_le();
-        let mask =
-            ((word + 0x3f3f3f3f_3f3f3f1f) & !(word + 0x25252525_25252505) & 0x80808080_80808080)
-                >> 2;
-        let result = (word | mask) & !(0x20 & mask);
+        let result = titlecase_lanes(word, 0x80);
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
+    }
+
+    /// Makes the string all lowercase except for the first character and
+    /// every character following a `-`, `_` or space, which are made
+    /// uppercase.
+    pub fn to_ascii_titlecase_words(self) -> Self {
+        let word = self.0.get().to_le();
+        let seps = lanes_eq(word, b'-') | lanes_eq(word, b'_') | lanes_eq(word, b' ');
+        let result = titlecase_lanes(word, (seps << 8) | 0x80);
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
+    }
+
+    /// Makes the string all lowercase except for the first character and
+    /// every character following a space, which are made uppercase.
+    pub fn to_ascii_titlecase_all_words(self) -> Self {
+        let word = self.0.get().to_le();
+        let result = titlecase_lanes(word, (lanes_eq(word, b' ') << 8) | 0x80);
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        // Again, could use #cfg to hand-roll a big-endian implementation.
+        let word = self.0.get().to_le();
+        Self::CAPACITY - (word.leading_zeros() / 8) as usize
+    }
+
+    /// Returns `CAPACITY`, the maximum length of the string in bytes.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// Always returns `false`, since a `TinyStr8` holds at least one character.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the string has the shape of a BCP-47 language
+    /// subtag: 2 to 8 ASCII letters, in any case.
+    ///
+    /// Like the other `is_*_subtag` methods, this only checks the syntax
+    /// from RFC 5646, not whether the subtag is in the IANA registry.
+    pub fn is_language_subtag(self) -> bool {
+        (2..=8).contains(&self.len()) && self.is_ascii_alphabetic()
+    }
+
+    /// Returns `true` if the string has the shape of a BCP-47 region
+    /// subtag: 2 ASCII letters, or exactly 3 ASCII digits.
+    pub fn is_region_subtag(self) -> bool {
+        match self.len() {
+            2 => self.is_ascii_alphabetic(),
+            3 => self.is_ascii_numeric(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the string has the shape of a BCP-47 script
+    /// subtag: exactly 4 ASCII letters, in any case.
+    ///
+    /// Use `canonicalize_subtag(SubtagKind::Script)` to get the canonical
+    /// titlecase form.
+    pub fn is_script_subtag(self) -> bool {
+        self.len() == 4 && self.is_ascii_alphabetic()
+    }
+
+    /// Returns `true` if the string has the shape of a BCP-47 variant
+    /// subtag: 5 to 8 ASCII alphanumerics, or 4 ASCII alphanumerics starting
+    /// with a digit.
+    pub fn is_variant_subtag(self) -> bool {
+        match self.len() {
+            4 => self.is_ascii_alphanumeric() && self.first().is_ascii_digit(),
+            5..=8 => self.is_ascii_alphanumeric(),
+            _ => false,
+        }
+    }
+
+    /// Returns the bytes of the string, without the trailing NUL padding.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { helpers::word_as_bytes(&self.0, self.len()) }
+    }
+
+    /// Returns `true` if every character is an ASCII digit, e.g. a UN M.49
+    /// region code like `"419"`. The NUL padding doesn't count.
+    pub fn is_ascii_numeric(self) -> bool {
+        helpers::is_ascii_numeric(self.0.get())
+    }
+
+    /// Returns the string with leading spaces (0x20) removed.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the string is all
+    /// spaces, since the result would be empty.
+    pub fn trim_ascii_start(self) -> Result<Self, Error> {
+        let word = self.0.get().to_le();
+        let result = helpers::trim_start_lanes(word, helpers::non_space_lanes(word))
+            .ok_or(Error::InvalidSize { len: 0, max: 8 })?;
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(result)))) }
+    }
+
+    /// Returns the string with trailing spaces (0x20) removed.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the string is all
+    /// spaces, since the result would be empty.
+    pub fn trim_ascii_end(self) -> Result<Self, Error> {
+        let word = self.0.get().to_le();
+        let result = helpers::trim_end_lanes(word, helpers::non_space_lanes(word))
+            .ok_or(Error::InvalidSize { len: 0, max: 8 })?;
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(result)))) }
+    }
+
+    /// Returns the string with leading and trailing spaces (0x20) removed.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the string is all
+    /// spaces, since the result would be empty.
+    pub fn trim_ascii(self) -> Result<Self, Error> {
+        self.trim_ascii_end()?.trim_ascii_start()
+    }
+
+    /// Returns the string bytes followed by NUL padding up to `CAPACITY`.
+    ///
+    /// This is the same as `u64::from(self).to_le_bytes()`.
+    pub fn to_bytes(self) -> [u8; 8] {
+        self.0.get().to_ne_bytes()
+    }
+
+    /// Creates a `TinyStr8` from string bytes followed by NUL padding, as
+    /// produced by `to_bytes`.
+    ///
+    /// Trailing NULs are treated as padding, while interior NULs, non-ASCII
+    /// bytes and an all-NUL array are rejected.
+    pub fn from_bytes_padded(bytes: [u8; 8]) -> Result<Self, Error> {
+        let word = u64::from_le_bytes(bytes);
+        if word == 0 {
+            return Err(Error::InvalidSize { len: 0, max: 8 });
+        }
+        let high = helpers::non_ascii_lanes(word);
+        if high != 0 {
+            return Err(Error::NonAscii {
+                index: helpers::first_lane(high),
+            });
+        }
+        Self::from_le_word(word, 8 - (word.leading_zeros() / 8) as usize)
+    }
+
+    /// Returns `true` if the string contains no lowercase ASCII letters,
+    /// i.e. if `s.to_ascii_uppercase() == s`.
+    ///
+    /// Digits and punctuation are allowed, so `"419"` is both uppercase and
+    /// lowercase.
+    pub fn is_ascii_uppercase(self) -> bool {
+        helpers::is_ascii_uppercase(self.0.get())
+    }
+
+    /// Returns `true` if the string contains no uppercase ASCII letters,
+    /// i.e. if `s.to_ascii_lowercase() == s`.
+    ///
+    /// Digits and punctuation are allowed, so `"419"` is both lowercase and
+    /// uppercase.
+    pub fn is_ascii_lowercase(self) -> bool {
+        helpers::is_ascii_lowercase(self.0.get())
+    }
+
+    /// Returns `true` if the string contains both uppercase and lowercase
+    /// ASCII letters.
+    pub fn is_ascii_mixed_case(self) -> bool {
+        !self.is_ascii_uppercase() && !self.is_ascii_lowercase()
+    }
+
+    /// Replaces every occurrence of `old` with `new`.
+    ///
+    /// Returns `Err(Error::NonAscii { .. })` if `new` is not ASCII and
+    /// `Err(Error::InvalidNull { .. })` if `new` is NUL, with `index` set to
+    /// the first occurrence of `old` (or 0 if there is none).
+    pub fn replace_char(self, old: char, new: char) -> Result<Self, Error> {
+        if !new.is_ascii() {
+            let index = self.find(old).unwrap_or(0);
+            return Err(Error::NonAscii { index });
+        }
+        if new == '\0' {
+            let index = self.find(old).unwrap_or(0);
+            return Err(Error::InvalidNull { index });
+        }
+        if !old.is_ascii() || old == '\0' {
+            return Ok(self);
+        }
+        let word = self.0.get().to_le();
+        let result = helpers::replace_lanes(word, lanes_eq(word, old as u8), new as u8);
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(result)))) }
+    }
+
+    /// Returns an iterator over the bytes of the string.
+    #[inline]
+    pub fn bytes(self) -> Bytes {
+        Bytes::new(u128::from(self.0.get().to_le()), self.len())
+    }
+
+    /// Returns `true` if every character is an ASCII hex digit: `0-9`,
+    /// `a-f` or `A-F`.
+    pub fn is_ascii_hexdigit(self) -> bool {
+        helpers::is_ascii_hexdigit(self.0.get())
+    }
+
+    /// Makes every uppercase ASCII letter lowercase and vice versa.
+    pub fn to_ascii_swapcase(self) -> Self {
+        let result = helpers::to_ascii_swapcase(self.0.get());
+        unsafe { Self(NonZeroU64::new_unchecked(result)) }
+    }
+
+    /// Parses `text` like `FromStr`, but additionally rejects ASCII control
+    /// characters (bytes below 0x20 and DEL) with `Error::NonGraphic`.
+    pub fn from_str_graphic(text: &str) -> Result<Self, Error> {
+        let s: Self = text.parse()?;
+        let word = s.0.get().to_le();
+        let control = helpers::control_lanes(word);
+        if control != 0 {
+            return Err(Error::NonGraphic {
+                index: helpers::first_lane(control),
+            });
+        }
+        Ok(s)
+    }
+
+    /// Returns an iterator over the chars of the string.
+    #[inline]
+    pub fn chars(self) -> Chars {
+        Chars::new(u128::from(self.0.get().to_le()), self.len())
+    }
+
+    /// Returns the char at `idx`, or `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<char> {
+        self.byte_at(idx).map(char::from)
+    }
+
+    /// Returns the byte at `idx`, or `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn byte_at(&self, idx: usize) -> Option<u8> {
+        if idx >= self.len() {
+            return None;
+        }
+        Some((self.0.get().to_le() >> (8 * idx)) as u8)
+    }
+
+    /// Returns the first char of the string.
+    #[inline(always)]
+    pub fn first(&self) -> char {
+        char::from(self.0.get().to_le() as u8)
+    }
+
+    /// Returns the last char of the string.
+    #[inline(always)]
+    pub fn last(&self) -> char {
+        char::from((self.0.get().to_le() >> (8 * (self.len() - 1))) as u8)
+    }
+
+    /// Returns `true` if the string starts with `pat`.
+    #[inline]
+    pub fn starts_with(&self, pat: impl AsRef<str>) -> bool {
+        let pat = pat.as_ref().as_bytes();
+        let len = pat.len();
+        if len > self.len() {
+            return false;
+        }
+        if len == 0 {
+            return true;
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(pat);
+        let mask = u64::MAX >> (8 * (8 - len));
+        ((self.0.get().to_le() ^ u64::from_le_bytes(bytes)) & mask) == 0
+    }
+
+    /// Returns `true` if the string ends with `pat`.
+    #[inline]
+    pub fn ends_with(&self, pat: impl AsRef<str>) -> bool {
+        let pat = pat.as_ref().as_bytes();
+        let len = pat.len();
+        if len > self.len() {
+            return false;
+        }
+        if len == 0 {
+            return true;
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(pat);
+        let mask = u64::MAX >> (8 * (8 - len));
+        let word = self.0.get().to_le() >> (8 * (self.len() - len));
+        ((word ^ u64::from_le_bytes(bytes)) & mask) == 0
+    }
+
+    /// Returns `true` if the first char of the string is `c`.
+    #[inline]
+    pub fn starts_with_char(&self, c: char) -> bool {
+        self.first() == c
+    }
+
+    /// Returns `true` if the last char of the string is `c`.
+    #[inline]
+    pub fn ends_with_char(&self, c: char) -> bool {
+        self.last() == c
+    }
+
+    /// Parses `text`, replacing every non-ASCII byte with `replacement`.
+    ///
+    /// This is lossy: unlike `FromStr`, non-ASCII input is silently
+    /// substituted rather than rejected, one `replacement` per byte of the
+    /// UTF-8 encoding. The length limits and the NUL check still apply, and
+    /// `replacement` must itself be ASCII and non-NUL; if it isn't, the
+    /// error `index` is that of the first byte it would have replaced.
+    pub fn from_str_lossy(text: &str, replacement: u8) -> Result<Self, Error> {
+        if !replacement.is_ascii() || replacement == 0 {
+            let index = text.bytes().position(|b| !b.is_ascii()).unwrap_or(0);
+            if replacement == 0 {
+                return Err(Error::InvalidNull { index });
+            }
+            return Err(Error::NonAscii { index });
+        }
+        let len = text.len();
+        if !(1..=8).contains(&len) {
+            return Err(Error::InvalidSize { len, max: 8 });
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(text.as_bytes());
+        let word = u64::from_le_bytes(bytes);
+        let word = helpers::replace_lanes(word, helpers::non_ascii_lanes(word), replacement);
+        Self::from_le_word(word, len)
+    }
+
+    /// Creates a string of `count` copies of `c`.
+    pub fn repeat_char(c: char, count: usize) -> Result<Self, Error> {
+        if !(1..=8).contains(&count) {
+            return Err(Error::InvalidSize { len: count, max: 8 });
+        }
+        if !c.is_ascii() {
+            return Err(Error::NonAscii { index: 0 });
+        }
+        if c == '\0' {
+            return Err(Error::InvalidNull { index: 0 });
+        }
+        let word: u64 = helpers::repeat_lanes(c as u8, count);
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns the string with `prefix` removed, or `None` if it doesn't
+    /// start with `prefix`.
+    ///
+    /// Since the result can't be empty, stripping the whole string also
+    /// returns `None`.
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Self> {
+        if prefix.len() >= self.len() || !self.starts_with(prefix) {
+            return None;
+        }
+        let word = self.0.get().to_le() >> (8 * prefix.len());
+        unsafe { Some(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns the string with `suffix` removed, or `None` if it doesn't
+    /// end with `suffix`.
+    ///
+    /// Since the result can't be empty, stripping the whole string also
+    /// returns `None`.
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Self> {
+        if suffix.len() >= self.len() || !self.ends_with(suffix) {
+            return None;
+        }
+        let len = self.len() - suffix.len();
+        let word = self.0.get().to_le() & (u64::MAX >> (8 * (8 - len)));
+        unsafe { Some(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Parses the first `CAPACITY` bytes of `text`, silently dropping the
+    /// rest.
+    ///
+    /// The caller loses any data past `CAPACITY`. Since the content is ASCII,
+    /// truncation always falls on a char boundary; non-ASCII or NUL bytes in
+    /// the kept part are still rejected.
+    pub fn from_str_truncating(text: &str) -> Result<Self, Error> {
+        let len = text.len().min(8);
+        if len == 0 {
+            return Err(Error::InvalidSize { len: 0, max: 8 });
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(&text.as_bytes()[..len]);
+        let word = u64::from_le_bytes(bytes);
+        let high = helpers::non_ascii_lanes(word);
+        if high != 0 {
+            return Err(Error::NonAscii {
+                index: helpers::first_lane(high),
+            });
+        }
+        Self::from_le_word(word, len)
+    }
+
+    /// Wraps `word`, the little-endian bytes of an ASCII string, after
+    /// checking its first `len` bytes are non-NUL.
+    #[inline(always)]
+    fn from_le_word(word: u64, len: usize) -> Result<Self, Error> {
+        let nulls = helpers::nul_lanes(word, len);
+        if nulls != 0 {
+            return Err(Error::InvalidNull {
+                index: helpers::first_lane(nulls),
+            });
+        }
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns `true` if `needle` is a substring of the string.
+    pub fn contains(&self, needle: &str) -> bool {
+        let len = needle.len();
+        if len == 0 {
+            return true;
+        }
+        if len > self.len() {
+            return false;
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(needle.as_bytes());
+        let pattern = u64::from_le_bytes(bytes);
+        let mask = u64::MAX >> (8 * (8 - len));
+        let word = self.0.get().to_le();
+        (0..=self.len() - len).any(|i| (((word >> (8 * i)) ^ pattern) & mask) == 0)
+    }
+
+    /// Replaces every occurrence of `from` with `to`.
+    ///
+    /// Unlike `replace_char`, both chars must be ASCII: a non-ASCII `from`
+    /// or `to` returns `Err(Error::NonAscii { .. })`, and a NUL `to` returns
+    /// `Err(Error::InvalidNull { .. })`. A non-ASCII `from` reports index 0.
+    pub fn replace(self, from: char, to: char) -> Result<Self, Error> {
+        if !from.is_ascii() {
+            return Err(Error::NonAscii { index: 0 });
+        }
+        self.replace_char(from, to)
+    }
+
+    /// Returns the index of the first occurrence of `c`, or `None` if the
+    /// string doesn't contain it.
+    #[inline]
+    pub fn find(&self, c: char) -> Option<usize> {
+        if !c.is_ascii() || c == '\0' {
+            return None;
+        }
+        let lanes = lanes_eq(self.0.get().to_le(), c as u8);
+        if lanes == 0 {
+            return None;
+        }
+        Some(helpers::first_lane(lanes))
+    }
+
+    /// Returns the index of the last occurrence of `c`, or `None` if the
+    /// string doesn't contain it.
+    #[inline]
+    pub fn rfind(&self, c: char) -> Option<usize> {
+        if !c.is_ascii() || c == '\0' {
+            return None;
+        }
+        let lanes = lanes_eq(self.0.get().to_le(), c as u8);
+        if lanes == 0 {
+            return None;
+        }
+        Some(helpers::last_lane(lanes))
+    }
+
+    /// Returns `true` if the string contains the byte `b`.
+    ///
+    /// NUL is never reported, even though the padding lanes hold it.
+    #[inline]
+    pub fn contains_byte(&self, b: u8) -> bool {
+        b != 0 && lanes_eq(self.0.get().to_le(), b) != 0
+    }
+
+    /// Returns `true` if the string contains `c`.
+    #[inline]
+    pub fn contains_char(&self, c: char) -> bool {
+        c.is_ascii() && self.contains_byte(c as u8)
+    }
+
+    /// Parses the first `CAPACITY` bytes of `text`, replacing every
+    /// non-ASCII or NUL byte with `'?'`.
+    ///
+    /// This combines `from_str_truncating` with a fixed-replacement
+    /// `from_str_lossy`, so every non-empty input is representable and only
+    /// an empty `text` returns `None`.
+    pub fn from_str_lossy_truncating(text: &str) -> Option<Self> {
+        let len = text.len().min(8);
+        if len == 0 {
+            return None;
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(&text.as_bytes()[..len]);
+        let word = u64::from_le_bytes(bytes);
+        let mask: u64 = helpers::prefix_lanes(len);
+        let bad = (helpers::non_ascii_lanes(word) | lanes_eq(word, 0)) & mask;
+        let word = helpers::replace_lanes(word, bad, b'?');
+        unsafe { Some(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns an iterator over the segments of the string separated by
+    /// `sep`.
+    ///
+    /// Each segment is a `TinyStr8` carved out of the backing integer without
+    /// re-validation. Since a `TinyStr8` can't be empty, empty segments (from
+    /// leading, trailing or adjacent separators) are skipped.
+    #[inline]
+    pub fn split(self, sep: char) -> Split<Self> {
+        Split::new(self, sep)
+    }
+
+    /// Splits the string around the first occurrence of `sep`.
+    ///
+    /// Returns `None` if `sep` is absent, or if it is the first or last
+    /// character, since neither side can be empty.
+    pub fn split_once(&self, sep: char) -> Option<(Self, Self)> {
+        let i = self.find(sep)?;
+        if i == 0 {
+            return None;
+        }
+        let word = self.0.get().to_le();
+        let head = word & !(u64::MAX << (8 * i));
+        let tail = NonZeroU64::new(u64::from_le(
+            word.checked_shr(8 * (i as u32 + 1)).unwrap_or(0),
+        ))?;
+        unsafe {
+            Some((
+                Self(NonZeroU64::new_unchecked(u64::from_le(head))),
+                Self(tail),
+            ))
+        }
+    }
+
+    /// Splits the string into the first `idx` bytes and the rest, or `None`
+    /// for the rest if `idx` is the full length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is 0, since the head can't be empty, or greater than
+    /// `len`, like `str::split_at`.
+    pub fn split_at(&self, idx: usize) -> (Self, Option<Self>) {
+        assert!(
+            idx != 0 && idx <= self.len(),
+            "split index {} out of range for length {}",
+            idx,
+            self.len()
+        );
+        let word = self.0.get().to_le();
+        let head = word & (u64::MAX >> (8 * (8 - idx)));
+        let tail = NonZeroU64::new(u64::from_le(word.checked_shr(8 * idx as u32).unwrap_or(0)));
+        unsafe {
+            (
+                Self(NonZeroU64::new_unchecked(u64::from_le(head))),
+                tail.map(Self),
+            )
+        }
+    }
+
+    /// Returns the string with its characters in reverse order.
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        let word = self.0.get().to_le().swap_bytes() >> (8 * (8 - self.len()));
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(word))) }
+    }
+
+    /// Collects `iter` into a `TinyStr8`, failing on the first char that
+    /// doesn't fit or isn't valid, or if `iter` is empty.
+    ///
+    /// This is the fallible counterpart to `collect::<TinyStr8>()`, which panics.
+    pub fn try_from_chars<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, Error> {
+        iter.into_iter().collect::<TinyStr8Collector>().try_build()
+    }
+
+    /// Returns the string repeated `n` times.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if `n` is 0 or the result
+    /// would be longer than `CAPACITY`. Use `repeat16` to repeat into a
+    /// wider type.
+    pub fn repeat(self, n: usize) -> Result<Self, Error> {
+        let len = self.len();
+        let total = len.saturating_mul(n);
+        if !(1..=8).contains(&total) {
+            return Err(Error::InvalidSize { len: total, max: 8 });
+        }
+        let mut word = self.0.get().to_le();
+        let mut filled = len;
+        while filled < total {
+            word |= word << (8 * filled);
+            filled *= 2;
+        }
+        let word = word & (u64::MAX >> (8 * (8 - total)));
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns the string repeated `n` times as a `TinyStr16`.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if `n` is 0 or the result
+    /// would be longer than 16 bytes. With `n == 1` this is just the
+    /// widening `TinyStr16::from(self)`.
+    pub fn repeat16(self, n: usize) -> Result<TinyStr16, Error> {
+        if n == 1 {
+            return Ok(TinyStr16::from(self));
+        }
+        TinyStr16::from(self).repeat(n)
+    }
+
+    /// Appends `other` to the string.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the combined length
+    /// exceeds `CAPACITY`.
+    pub fn try_concat(self, other: Self) -> Result<Self, Error> {
+        let len = self.len();
+        let total = len + other.len();
+        if total > 8 {
+            return Err(Error::InvalidSize { len: total, max: 8 });
+        }
+        let word = self.0.get().to_le() | (other.0.get().to_le() << (8 * len));
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Appends `other` to the string, widening into a `TinyStr16`, which
+    /// always has room for both.
+    pub fn concat(self, other: Self) -> TinyStr16 {
+        let word = u128::from(u64::from(self)) | (u128::from(u64::from(other)) << (8 * self.len()));
+        unsafe { TinyStr16::new_unchecked(word) }
+    }
+
+    /// Returns the rest of the string if it starts with `c`, or `None`.
+    #[inline]
+    pub fn strip_prefix_char(&self, c: char) -> Option<&str> {
+        if self.first() != c {
+            return None;
+        }
+        Some(&self.deref()[1..])
+    }
+
+    /// Returns the rest of the string if it ends with `c`, or `None`.
+    #[inline]
+    pub fn strip_suffix_char(&self, c: char) -> Option<&str> {
+        if self.last() != c {
+            return None;
+        }
+        let s = self.deref();
+        Some(&s[..s.len() - 1])
+    }
+
+    /// Returns the rest of the string if it starts with `prefix`, or `None`.
+    ///
+    /// Unlike `strip_prefix`, this borrows the remainder, which may be empty.
+    #[inline]
+    pub fn strip_prefix_str(&self, prefix: &str) -> Option<&str> {
+        if !self.starts_with(prefix) {
+            return None;
+        }
+        Some(&self.deref()[prefix.len()..])
+    }
+
+    /// Returns the start of the string if it ends with `suffix`, or `None`.
+    ///
+    /// Unlike `strip_suffix`, this borrows the remainder, which may be empty.
+    #[inline]
+    pub fn strip_suffix_str(&self, suffix: &str) -> Option<&str> {
+        if !self.ends_with(suffix) {
+            return None;
+        }
+        let s = self.deref();
+        Some(&s[..s.len() - suffix.len()])
+    }
+
+    /// Returns the backing integer as zero-padded hex, e.g.
+    /// `"0x0000000000006e65"` for a `TinyStr8` holding `"en"`.
+    ///
+    /// The integer is the little-endian value, as returned by `u64::from`.
+    pub fn as_raw_hex(&self) -> String {
+        format!("{:#0width$x}", self, width = 2 + 2 * 8)
+    }
+
+    /// Appends `c` to the string.
+    ///
+    /// Fails like `TinyStrBuf8::push` if `c` is not ASCII, is NUL, or
+    /// doesn't fit.
+    pub fn try_push(self, c: char) -> Result<Self, Error> {
+        let len = self.len();
+        if !c.is_ascii() {
+            return Err(Error::NonAscii { index: len });
+        }
+        if c == '\0' {
+            return Err(Error::InvalidNull { index: len });
+        }
+        if len == 8 {
+            return Err(Error::InvalidSize {
+                len: len + 1,
+                max: 8,
+            });
+        }
+        let word = self.0.get().to_le() | (u64::from(c as u8) << (8 * len));
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Appends `s` to the string.
+    ///
+    /// Errors report indices and lengths relative to the combined string.
+    pub fn try_push_str(self, s: &str) -> Result<Self, Error> {
+        if s.is_empty() {
+            return Ok(self);
+        }
+        let len = self.len();
+        let other: Self = s.parse().map_err(|err| match err {
+            Error::InvalidSize { len: other, max } => Error::InvalidSize {
+                len: len + other,
+                max,
+            },
+            Error::InvalidNull { index } => Error::InvalidNull { index: len + index },
+            Error::NonAscii { index } => Error::NonAscii { index: len + index },
+            err => err,
+        })?;
+        self.try_concat(other)
+    }
+
+    /// Returns the number of times `c` occurs in the string.
+    #[inline]
+    pub fn count_char(&self, c: char) -> usize {
+        if !c.is_ascii() || c == '\0' {
+            return 0;
+        }
+        lanes_eq(self.0.get().to_le(), c as u8).count_ones() as usize
+    }
+
+    /// Formats `args` into a `TinyStr8` without allocating.
+    ///
+    /// Fails rather than truncating if the output is empty, longer than
+    /// `CAPACITY`, or contains non-ASCII or NUL characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Display` impl in `args` returns an error, like
+    /// `ToString::to_string`.
+    pub fn from_fmt(args: fmt::Arguments) -> Result<Self, Error> {
+        let mut collector = TinyStr8Collector::new();
+        let result = fmt::write(&mut collector, args);
+        let s = collector.try_build()?;
+        result.expect("a Display implementation returned an error unexpectedly");
+        Ok(s)
+    }
+
+    /// Captures the `Display` output of `value` into a `TinyStr8`, as
+    /// `from_fmt` does.
+    pub fn from_display(value: &impl fmt::Display) -> Result<Self, Error> {
+        Self::from_fmt(format_args!("{}", value))
+    }
+
+    /// Returns `true` if the string reads the same reversed.
+    #[inline]
+    pub fn is_palindrome(&self) -> bool {
+        *self == self.reverse()
+    }
+
+    /// Returns the string with leading ASCII whitespace removed, as
+    /// `str::trim_start` would, or `None` if it's all whitespace.
+    ///
+    /// Unlike `trim_ascii_start`, this also strips tabs, line breaks,
+    /// vertical tabs and form feeds.
+    pub fn trim_start(&self) -> Option<Self> {
+        let word = self.0.get().to_le();
+        let result = helpers::trim_start_lanes(word, helpers::non_whitespace_lanes(word))?;
+        unsafe { Some(Self(NonZeroU64::new_unchecked(u64::from_le(result)))) }
+    }
+
+    /// Returns the string with trailing ASCII whitespace removed, as
+    /// `str::trim_end` would, or `None` if it's all whitespace.
+    pub fn trim_end(&self) -> Option<Self> {
+        let word = self.0.get().to_le();
+        let result = helpers::trim_end_lanes(word, helpers::non_whitespace_lanes(word))?;
+        unsafe { Some(Self(NonZeroU64::new_unchecked(u64::from_le(result)))) }
+    }
+
+    /// Returns the string with leading and trailing ASCII whitespace
+    /// removed, as `str::trim` would, or `None` if it's all whitespace.
+    ///
+    /// This shadows `str::trim` on the dereferenced string; use
+    /// `as_str().trim()` to get a `&str` instead.
+    pub fn trim(&self) -> Option<Self> {
+        self.trim_end()?.trim_start()
+    }
+
+    /// Splits the string around the first occurrence of `delim`, like
+    /// `str::split_once`, or returns `None` if `delim` is absent.
+    ///
+    /// Unlike `split_once`, this borrows both halves, which may be empty.
+    #[inline]
+    pub fn split_once_str(&self, delim: char) -> Option<(&str, &str)> {
+        let i = self.find(delim)?;
+        let s = self.deref();
+        Some((&s[..i], &s[i + 1..]))
+    }
+
+    /// Returns `true` if the strings are equal ignoring ASCII case, like
+    /// `str::eq_ignore_ascii_case`.
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        helpers::to_ascii_lowercase(self.0.get()) == helpers::to_ascii_lowercase(other.0.get())
+    }
+
+    /// Returns `true` if `other` equals the string ignoring ASCII case.
+    ///
+    /// `other` is never equal if it isn't a valid `TinyStr8`.
+    #[inline]
+    pub fn eq_ignore_ascii_case_str(&self, other: &str) -> bool {
+        other
+            .parse::<Self>()
+            .is_ok_and(|other| self.eq_ignore_ascii_case(&other))
+    }
+
+    /// Returns the string bytes followed by NUL padding up to `N`, e.g. for
+    /// a fixed-width field in a packed binary format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is smaller than `CAPACITY`.
+    pub fn to_fixed_bytes<const N: usize>(self) -> [u8; N] {
+        assert!(N >= 8, "array is smaller than the capacity");
+        let mut bytes = [0; N];
+        bytes[..8].copy_from_slice(&self.to_bytes());
+        bytes
+    }
+
+    /// Creates a `TinyStr8` from string bytes followed by NUL padding up to `N`,
+    /// as produced by `to_fixed_bytes`.
+    ///
+    /// Validates like `from_bytes_padded`, and additionally returns
+    /// `Err(Error::InvalidSize { .. })` if the string doesn't fit.
+    pub fn from_fixed_bytes<const N: usize>(bytes: [u8; N]) -> Result<Self, Error> {
+        let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        if len > 8 {
+            return Err(Error::InvalidSize { len, max: 8 });
+        }
+        let mut padded = [0; 8];
+        padded[..len].copy_from_slice(&bytes[..len]);
+        Self::from_bytes_padded(padded)
+    }
+
+    /// Compares the strings ignoring ASCII case, so `"DE"` sorts between
+    /// `"cs"` and `"fr"`.
+    ///
+    /// Strings that differ only in case are ordered by `Ord`, which puts
+    /// uppercase first. This keeps the ordering total: it only returns
+    /// `Ordering::Equal` for equal strings.
+    #[inline]
+    pub fn cmp_ignore_ascii_case(&self, other: &Self) -> Ordering {
+        helpers::cmp(
+            helpers::to_ascii_lowercase(self.0.get()),
+            helpers::to_ascii_lowercase(other.0.get()),
+        )
+        .then_with(|| self.cmp(other))
+    }
+
+    /// Creates a `TinyStr8` with `lo` as its first 4 bytes and `hi` as the
+    /// rest.
+    ///
+    /// Unlike `concat4`, the halves keep their fixed positions, so `lo` must
+    /// be full; otherwise its padding would end up inside the string and
+    /// this returns `Err(Error::InvalidNull { .. })`.
+    pub fn from_halves(lo: TinyStr4, hi: TinyStr4) -> Result<Self, Error> {
+        let len = lo.len();
+        if len < 4 {
+            return Err(Error::InvalidNull { index: len });
+        }
+        Ok(lo.concat(hi))
+    }
+
+    /// Splits the string into its first 4 bytes and the rest, or `None`
+    /// for the rest if the string is no longer than 4 bytes.
+    ///
+    /// This is the inverse of `from_halves`.
+    pub fn into_halves(self) -> (TinyStr4, Option<TinyStr4>) {
+        let word = self.0.get().to_le();
+        let lo = word as u32;
+        let hi = (word >> 32) as u32;
+        unsafe {
+            (
+                TinyStr4::new_unchecked(lo),
+                (hi != 0).then(|| TinyStr4::new_unchecked(hi)),
+            )
+        }
+    }
+
+    /// Returns the string bytes followed by NUL padding, as a safe
+    /// `bytemuck` cast of the backing integer.
+    ///
+    /// `TinyStr8` can't be `Pod` or `Zeroable`: the all-zero array, and any array
+    /// holding an interior NUL or a non-ASCII byte, isn't a valid value.
+    /// So only this direction is a plain cast, and `from_byte_array`
+    /// validates.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn to_byte_array(self) -> [u8; 8] {
+        bytemuck::cast(self)
+    }
+
+    /// Creates a `TinyStr8` from the bytes returned by `to_byte_array`,
+    /// validating them like `from_bytes_padded`.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn from_byte_array(bytes: [u8; 8]) -> Result<Self, Error> {
+        Self::from_bytes_padded(bytes)
+    }
+
+    /// Returns the number of uppercase ASCII letters in the string.
+    #[inline]
+    pub fn count_uppercase(&self) -> usize {
+        helpers::count_uppercase(self.0.get())
+    }
+
+    /// Returns the number of lowercase ASCII letters in the string.
+    #[inline]
+    pub fn count_lowercase(&self) -> usize {
+        helpers::count_lowercase(self.0.get())
+    }
+
+    /// Returns the number of ASCII digits in the string.
+    #[inline]
+    pub fn count_digits(&self) -> usize {
+        helpers::count_digits(self.0.get())
+    }
+
+    /// Returns the number of ASCII letters in the string.
+    #[inline]
+    pub fn count_alphabetic(&self) -> usize {
+        helpers::count_alphabetic(self.0.get())
+    }
+
+    /// Parses `text` like `FromStr`, then additionally rejects the first
+    /// byte for which `check` returns `false` with
+    /// `Error::FailedValidation`.
+    ///
+    /// `check` only sees non-NUL ASCII bytes.
+    pub fn from_str_validating<F: Fn(u8) -> bool>(text: &str, check: F) -> Result<Self, Error> {
+        let s: Self = text.parse()?;
+        match s.as_bytes().iter().position(|&b| !check(b)) {
+            Some(index) => Err(Error::FailedValidation { index }),
+            None => Ok(s),
+        }
+    }
+
+    /// Returns `true` if every character is an ASCII letter, digit or `_`.
+    #[inline]
+    pub fn is_ascii_word_char(self) -> bool {
+        helpers::is_ascii_word(self.0.get())
+    }
+
+    /// Returns `true` if the string is an ASCII identifier: word characters
+    /// only, starting with a letter or `_`.
+    #[inline]
+    pub fn is_ascii_identifier_start(self) -> bool {
+        self.is_ascii_word_char() && !self.first().is_ascii_digit()
+    }
+
+    /// Returns `true` if the string can continue an ASCII identifier, i.e.
+    /// is all word characters; the same test as `is_ascii_word_char`.
+    #[inline]
+    pub fn is_ascii_identifier_continue(self) -> bool {
+        self.is_ascii_word_char()
+    }
+
+    /// Returns the value of the string as a decimal number, or `None` if it
+    /// isn't all ASCII digits.
+    ///
+    /// Leading zeros are allowed, so `"007"` is 7.
+    pub fn to_u64(&self) -> Option<u64> {
+        if !self.is_ascii_numeric() {
+            return None;
+        }
+        let digits = self.bytes().map(|b| u64::from(b - b'0'));
+        Some(digits.fold(0, |acc, d| acc * 10 + d))
+    }
+
+    /// Makes the string all lowercase except for the first character and
+    /// every character following `sep`, which are made uppercase, so
+    /// `"zh-hant-tw"` becomes `"Zh-Hant-Tw"` with `sep` of `'-'`.
+    ///
+    /// Like `to_ascii_titlecase`, a segment starting with a non-letter is
+    /// only lowercased. A non-ASCII or NUL `sep` never matches.
+    pub fn to_ascii_titlecase_segments(self, sep: char) -> Self {
+        let word = self.0.get().to_le();
+        let seps = if sep.is_ascii() && sep != '\0' {
+            lanes_eq(word, sep as u8)
+        } else {
+            0
+        };
+        let result = titlecase_lanes(word, (seps << 8) | 0x80);
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
+    }
+
+    /// Uppercases the first character if it's a letter, leaving the rest
+    /// of the string unchanged, so `"latn"` becomes `"Latn"`.
+    pub fn to_upper_first(self) -> Self {
+        let word = self.0.get().to_le();
+        let result = (word & !0xff) | (helpers::to_ascii_uppercase(word) & 0xff);
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
+    }
+
+    /// Lowercases the first character if it's a letter, leaving the rest
+    /// of the string unchanged.
+    pub fn to_lower_first(self) -> Self {
+        let word = self.0.get().to_le();
+        let result = (word & !0xff) | (helpers::to_ascii_lowercase(word) & 0xff);
         unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(result))) }
     }
+
+    /// Converts the string to the canonical case for a subtag of `kind`:
+    /// lowercase for languages and variants, titlecase for scripts and
+    /// uppercase for regions.
+    pub fn canonicalize_subtag(self, kind: SubtagKind) -> Self {
+        match kind {
+            SubtagKind::Language | SubtagKind::Variant => self.to_ascii_lowercase(),
+            SubtagKind::Script => self.to_ascii_titlecase(),
+            SubtagKind::Region => self.to_ascii_uppercase(),
+        }
+    }
+
+    /// Guesses which kind of BCP-47 subtag the string is from its length
+    /// and content, or returns `None` if it can't be a subtag.
+    ///
+    /// 2 or 3 letters are a language, 4 letters a script, 3 digits a
+    /// region, and 5 to 8 alphanumerics, or 4 starting with a digit, a
+    /// variant. Since 2 letters could be either a language or a region,
+    /// those are taken as a region only if already all uppercase.
+    pub fn infer_kind(self) -> Option<SubtagKind> {
+        let alpha = self.is_ascii_alphabetic();
+        match self.len() {
+            2 if alpha && self.is_ascii_uppercase() => Some(SubtagKind::Region),
+            2 | 3 if alpha => Some(SubtagKind::Language),
+            3 if self.is_ascii_numeric() => Some(SubtagKind::Region),
+            4 if alpha => Some(SubtagKind::Script),
+            _ if self.is_variant_subtag() => Some(SubtagKind::Variant),
+            _ => None,
+        }
+    }
+
+    /// Returns the string with its characters rotated `n` places to the
+    /// left, so that `"abcd".rotate_left(1)` is `"bcda"`.
+    ///
+    /// `n` is reduced modulo `len()`, so rotating by any multiple of the
+    /// length returns the string unchanged.
+    pub fn rotate_left(self, n: usize) -> Self {
+        let len = self.len();
+        let k = n % len;
+        if k == 0 {
+            return self;
+        }
+        let word = self.0.get().to_le();
+        let mask = u64::MAX >> (8 * (8 - len));
+        let rotated = ((word >> (8 * k)) | (word << (8 * (len - k)))) & mask;
+        unsafe { Self(NonZeroU64::new_unchecked(u64::from_le(rotated))) }
+    }
+
+    /// Returns the string with its characters rotated `n` places to the
+    /// right, so that `"abcd".rotate_right(1)` is `"dabc"`.
+    ///
+    /// `n` is reduced modulo `len()`, as in `rotate_left`.
+    pub fn rotate_right(self, n: usize) -> Self {
+        let len = self.len();
+        self.rotate_left(len - n % len)
+    }
+
+    /// Returns the string with `c` appended.
+    ///
+    /// This is `try_push` under the name that pairs with `with_prefix`.
+    #[inline]
+    pub fn with_suffix(self, c: char) -> Result<Self, Error> {
+        self.try_push(c)
+    }
+
+    /// Returns the string with `c` inserted at the front, shifting the
+    /// existing characters up by one.
+    ///
+    /// Fails like `try_push` if `c` is not ASCII, is NUL, or doesn't fit,
+    /// except that errors about `c` report index 0.
+    pub fn with_prefix(self, c: char) -> Result<Self, Error> {
+        let len = self.len();
+        if !c.is_ascii() {
+            return Err(Error::NonAscii { index: 0 });
+        }
+        if c == '\0' {
+            return Err(Error::InvalidNull { index: 0 });
+        }
+        if len == 8 {
+            return Err(Error::InvalidSize {
+                len: len + 1,
+                max: 8,
+            });
+        }
+        let word = (self.0.get().to_le() << 8) | u64::from(c as u8);
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
+
+    /// Returns the value of the string as a number in `radix`, or `None` if
+    /// any character isn't a digit in that radix.
+    ///
+    /// Letters are digits from 10 up, in either case, as in
+    /// `u64::from_str_radix`, but a leading `+` isn't accepted, matching
+    /// `to_u64`. Even 8 digits in radix 36 fit in a `u64`, so this never
+    /// overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    pub fn to_u64_radix(&self, radix: u32) -> Option<u64> {
+        assert!(
+            (2..=36).contains(&radix),
+            "to_u64_radix: radix must be in 2..=36, got {}",
+            radix
+        );
+        self.bytes().try_fold(0u64, |acc, b| {
+            let d = char::from(b).to_digit(radix)?;
+            acc.checked_mul(u64::from(radix))?.checked_add(u64::from(d))
+        })
+    }
+
+    /// Returns the number of leading bytes `self` and `other` have in
+    /// common.
+    ///
+    /// The first differing byte is the lowest nonzero lane of the XOR of
+    /// the two words. Since the padding is NUL, the result never exceeds
+    /// the shorter length.
+    #[inline]
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        let diff = self.0.get().to_le() ^ other.0.get().to_le();
+        let len = (diff.trailing_zeros() / 8) as usize;
+        len.min(self.len()).min(other.len())
+    }
+
+    /// Returns `true` if `pred` holds for every character; the same as
+    /// `self.chars().all(pred)`.
+    ///
+    /// Prefer the `is_ascii_*` methods where one fits, since they test
+    /// every character at once rather than one at a time.
+    #[inline]
+    pub fn all_chars(&self, pred: impl Fn(char) -> bool) -> bool {
+        self.chars().all(pred)
+    }
+
+    /// Returns `true` if `pred` holds for any character; the same as
+    /// `self.chars().any(pred)`.
+    #[inline]
+    pub fn any_char(&self, pred: impl Fn(char) -> bool) -> bool {
+        self.chars().any(pred)
+    }
+
+    /// Returns the string with each character replaced by `f` applied to it.
+    ///
+    /// Fails like `try_from_chars` if `f` returns a non-ASCII or NUL char,
+    /// reporting the index of that char.
+    pub fn map_chars(&self, f: impl Fn(char) -> char) -> Result<Self, Error> {
+        Self::try_from_chars(self.chars().map(f))
+    }
+
+    /// Returns `n` written in decimal, so 7 becomes `"7"`.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if `n` has more than
+    /// `CAPACITY` digits.
+    #[inline]
+    pub fn from_decimal(n: u32) -> Result<Self, Error> {
+        Self::from_decimal_padded(n, 1)
+    }
+
+    /// Returns `n` written in decimal and padded with leading zeros to at
+    /// least `width` digits, so 7 with a `width` of 3 becomes `"007"`.
+    ///
+    /// Like `format!("{:0width$}", n)`, a value with more than `width`
+    /// digits isn't truncated. Returns `Err(Error::InvalidSize { .. })` if
+    /// the result would be longer than `CAPACITY`.
+    pub fn from_decimal_padded(n: u32, width: usize) -> Result<Self, Error> {
+        let mut digits = 1;
+        let mut rest = n / 10;
+        while rest != 0 {
+            digits += 1;
+            rest /= 10;
+        }
+        let len = digits.max(width);
+        if len > 8 {
+            return Err(Error::InvalidSize { len, max: 8 });
+        }
+        let mut word: u64 = 0;
+        let mut rest = n;
+        for _ in 0..len {
+            word = (word << 8) | u64::from(b'0' + (rest % 10) as u8);
+            rest /= 10;
+        }
+        unsafe { Ok(Self(NonZeroU64::new_unchecked(u64::from_le(word)))) }
+    }
 }
 
 impl fmt::Display for TinyStr8 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.deref())
+        f.pad(self.deref())
     }
 }
 
@@ -77,13 +1282,7 @@ impl Deref for TinyStr8 {
 
     #[inline(always)]
     fn deref(&self) -> &str {
-        // Again, could use #cfg to hand-roll a big-endian implementation.
-        let word = self.0.get().to_le();
-        let len = (8 - word.leading_zeros() / 8) as usize;
-        unsafe {
-            let slice = core::slice::from_raw_parts(&self.0 as *const _ as *const u8, len);
-            std::str::from_utf8_unchecked(slice)
-        }
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
     }
 }
 
@@ -101,7 +1300,7 @@ impl PartialOrd for TinyStr8 {
 
 impl Ord for TinyStr8 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.get().to_be().cmp(&other.0.get().to_be())
+        helpers::cmp(self.0.get(), other.0.get())
     }
 }
 
@@ -111,8 +1310,8 @@ impl FromStr for TinyStr8 {
     #[inline(always)]
     fn from_str(text: &str) -> Result<Self, Self::Err> {
         let len = text.len();
-        if len < 1 || len > 8 {
-            return Err(Error::InvalidSize);
+        if !(1..=8).contains(&len) {
+            return Err(Error::InvalidSize { len, max: 8 });
         }
         unsafe {
             let mut word: u64 = 0;
@@ -121,19 +1320,321 @@ impl FromStr for TinyStr8 {
             // TODO: could do this with #cfg(target_endian), but this is clearer and
             // more confidence-inspiring.
             let mask = u64::from_le(mask);
-            if (word & mask) != 0 {
-                return Err(Error::NonAscii);
+            let high = word & mask;
+            if high != 0 {
+                return Err(Error::NonAscii {
+                    index: (high.to_le().trailing_zeros() / 8) as usize,
+                });
             }
-            if ((mask - word) & mask) != 0 {
-                return Err(Error::InvalidNull);
+            let nulls = (mask - word) & mask;
+            if nulls != 0 {
+                return Err(Error::InvalidNull {
+                    index: (nulls.to_le().trailing_zeros() / 8) as usize,
+                });
             }
             Ok(Self(NonZeroU64::new_unchecked(word)))
         }
     }
 }
 
-impl Into<u64> for TinyStr8 {
-    fn into(self) -> u64 {
-        self.0.get().to_le()
+impl From<TinyStr8> for u64 {
+    fn from(input: TinyStr8) -> Self {
+        input.0.get().to_le()
+    }
+}
+
+impl AsRef<[u8]> for TinyStr8 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for TinyStr8 {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.deref()
+    }
+}
+
+/// Hashes the same as the equivalent `str`, as `Borrow<str>` requires.
+impl Hash for TinyStr8 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+impl fmt::LowerHex for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0.get().to_le(), f)
+    }
+}
+
+impl fmt::UpperHex for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0.get().to_le(), f)
+    }
+}
+
+impl fmt::Binary for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Binary::fmt(&self.0.get().to_le(), f)
+    }
+}
+
+impl fmt::Octal for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Octal::fmt(&self.0.get().to_le(), f)
+    }
+}
+
+impl Index<Range<usize>> for TinyStr8 {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, index: Range<usize>) -> &str {
+        &self.deref()[index]
+    }
+}
+
+impl Index<RangeFrom<usize>> for TinyStr8 {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, index: RangeFrom<usize>) -> &str {
+        &self.deref()[index]
+    }
+}
+
+impl Index<RangeTo<usize>> for TinyStr8 {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, index: RangeTo<usize>) -> &str {
+        &self.deref()[index]
+    }
+}
+
+impl Index<RangeFull> for TinyStr8 {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, _index: RangeFull) -> &str {
+        self.deref()
+    }
+}
+
+impl Iterator for Split<TinyStr8> {
+    type Item = TinyStr8;
+
+    fn next(&mut self) -> Option<TinyStr8> {
+        loop {
+            let rest = self.advance(None)?;
+            let word = rest.0.get().to_le();
+            let (head, tail) = match rest.find(self.sep()) {
+                Some(i) => (
+                    word & !(u64::MAX << (8 * i)),
+                    word.checked_shr(8 * (i as u32 + 1)).unwrap_or(0),
+                ),
+                None => (word, 0),
+            };
+            self.advance(NonZeroU64::new(u64::from_le(tail)).map(TinyStr8));
+            if let Some(head) = NonZeroU64::new(u64::from_le(head)) {
+                return Some(TinyStr8(head));
+            }
+        }
+    }
+}
+
+impl FusedIterator for Split<TinyStr8> {}
+
+impl From<TinyStr8> for String {
+    /// Copies the string bytes into a `String` of exactly `len` capacity,
+    /// bypassing the formatting machinery used by `to_string`.
+    #[inline]
+    fn from(input: TinyStr8) -> Self {
+        input.as_str().to_owned()
+    }
+}
+
+// A `TinyStr8` is a `#[repr(transparent)]` `NonZeroU64`, so it has no padding or
+// uninitialized bytes and can always be viewed as a `u64`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for TinyStr8 {}
+
+// Only the `u64`s whose bytes form a NUL-padded ASCII string are valid, so
+// casts from `u64` go through `from_bytes_padded`'s checks.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for TinyStr8 {
+    type Bits = u64;
+
+    #[inline]
+    fn is_valid_bit_pattern(bits: &u64) -> bool {
+        Self::from_bytes_padded(bits.to_ne_bytes()).is_ok()
+    }
+}
+
+impl<'a> From<&'a TinyStr8> for Cow<'a, str> {
+    #[inline]
+    fn from(input: &'a TinyStr8) -> Self {
+        Cow::Borrowed(input.as_str())
+    }
+}
+
+impl From<TinyStr8> for Cow<'static, str> {
+    #[inline]
+    fn from(input: TinyStr8) -> Self {
+        Cow::Owned(input.into())
+    }
+}
+
+/// Generates a length of 1 to 8 from one byte, then that many ASCII bytes
+/// in `1..=127`, one byte each.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TinyStr8 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(1..=8)?;
+        let mut word: u64 = 0;
+        for i in 0..len {
+            word |= u64::from(u.int_in_range(1..=127u8)?) << (8 * i);
+        }
+        unsafe { Ok(Self::new_unchecked(word)) }
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, Some(1 + 8))
+    }
+}
+
+impl From<TinyStr4> for TinyStr8 {
+    /// Widens a `TinyStr4` without re-validating it.
+    #[inline]
+    fn from(input: TinyStr4) -> Self {
+        unsafe { Self::new_unchecked(u64::from(u32::from(input))) }
+    }
+}
+
+impl Add for TinyStr8 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the combined length exceeds `CAPACITY`; use `try_concat`
+    /// to handle that case.
+    fn add(self, other: Self) -> Self {
+        self.try_concat(other)
+            .expect("concatenation exceeds CAPACITY")
+    }
+}
+
+impl PartialEq for CaseInsensitive<TinyStr8> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_ignore_ascii_case(other)
+    }
+}
+
+impl Eq for CaseInsensitive<TinyStr8> {}
+
+/// Hashes the lowercased string, so values equal ignoring case hash alike.
+impl Hash for CaseInsensitive<TinyStr8> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_ascii_lowercase().hash(state)
+    }
+}
+
+impl PartialOrd for CaseInsensitive<TinyStr8> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the lowercased strings, without `cmp_ignore_ascii_case`'s
+/// tie-break, so that it agrees with `Eq`.
+impl Ord for CaseInsensitive<TinyStr8> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_ascii_lowercase().cmp(&other.to_ascii_lowercase())
+    }
+}
+
+impl From<TinyStr8> for CaseInsensitive<TinyStr8> {
+    #[inline]
+    fn from(input: TinyStr8) -> Self {
+        Self::new(input)
+    }
+}
+
+/// Bakes to `tinystr::TinyStr8::new_unchecked` on the little-endian word, which
+/// rebuilds the same value on any platform.
+#[cfg(feature = "databake")]
+impl databake::Bake for TinyStr8 {
+    fn bake(&self, ctx: &databake::CrateEnv) -> databake::TokenStream {
+        ctx.insert("tinystr");
+        let word = u64::from(*self);
+        databake::quote! {
+            unsafe { tinystr::TinyStr8::new_unchecked(#word) }
+        }
+    }
+}
+
+#[cfg(feature = "databake")]
+impl databake::BakeSize for TinyStr8 {
+    #[inline]
+    fn borrows_size(&self) -> usize {
+        0
+    }
+}
+
+impl PartialEq<TinyStr4> for TinyStr8 {
+    #[inline]
+    fn eq(&self, other: &TinyStr4) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl PartialEq<TinyStr16> for TinyStr8 {
+    #[inline]
+    fn eq(&self, other: &TinyStr16) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl PartialOrd<TinyStr4> for TinyStr8 {
+    #[inline]
+    fn partial_cmp(&self, other: &TinyStr4) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl PartialOrd<TinyStr16> for TinyStr8 {
+    #[inline]
+    fn partial_cmp(&self, other: &TinyStr16) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+/// Writes the little-endian `u64` word.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for TinyStr8 {
+    #[inline]
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        u64::from(*self).serialize(writer)
+    }
+}
+
+/// Reads the little-endian `u64` word and validates it like
+/// `from_bytes_padded`, failing with `io::ErrorKind::InvalidData`.
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for TinyStr8 {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let word = u64::deserialize_reader(reader)?;
+        Self::from_bytes_padded(word.to_le_bytes()).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{:?}", err))
+        })
     }
 }
@@ -0,0 +1,253 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+use crate::Error;
+use crate::TinyStr4;
+
+/// A growable string of up to 4 non-NUL ASCII characters, used to build a
+/// `TinyStr4` incrementally.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TinyStrBuf4(u32);
+
+/// A `TinyStrBuf4` used to `write!` formatted text into a `TinyStr4`
+/// without a `String`; call `finish` to get the result.
+pub type TinyStr4Builder = TinyStrBuf4;
+
+impl TinyStrBuf4 {
+    /// Creates an empty buffer.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns the length of the buffer in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        4 - (self.0.to_le().leading_zeros() / 8) as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.deref()
+    }
+
+    /// Appends `c` to the end of the buffer.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the buffer is full.
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        let len = self.len();
+        if !c.is_ascii() {
+            return Err(Error::NonAscii { index: len });
+        }
+        if c == '\0' {
+            return Err(Error::InvalidNull { index: len });
+        }
+        if len == 4 {
+            return Err(Error::InvalidSize {
+                len: len + 1,
+                max: 4,
+            });
+        }
+        self.0 = u32::from_le(self.0.to_le() | (u32::from(c as u8) << (8 * len)));
+        Ok(())
+    }
+
+    /// Appends `s` to the end of the buffer.
+    ///
+    /// Either all of `s` is appended or, on error, none of it.
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        let len = self.len();
+        if let Some(i) = s.bytes().position(|b| !b.is_ascii()) {
+            return Err(Error::NonAscii { index: len + i });
+        }
+        if let Some(i) = s.bytes().position(|b| b == 0) {
+            return Err(Error::InvalidNull { index: len + i });
+        }
+        if len + s.len() > 4 {
+            return Err(Error::InvalidSize {
+                len: len + s.len(),
+                max: 4,
+            });
+        }
+        let mut word = self.0.to_le();
+        for (i, b) in s.bytes().enumerate() {
+            word |= u32::from(b) << (8 * (len + i));
+        }
+        self.0 = u32::from_le(word);
+        Ok(())
+    }
+
+    /// Returns how many more chars fit in the buffer.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        4 - self.len()
+    }
+
+    /// Removes the last char from the buffer and returns it, or `None` if
+    /// the buffer is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        if self.is_empty() {
+            return None;
+        }
+        let shift = 8 * (self.len() - 1);
+        let word = self.0.to_le();
+        self.0 = u32::from_le(word & !(0xff << shift));
+        Some(char::from((word >> shift) as u8))
+    }
+
+    /// Empties the buffer.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    /// Converts the buffer into a `TinyStr4`.
+    ///
+    /// Returns `Err(Error::InvalidSize { .. })` if the buffer is empty.
+    pub fn build(self) -> Result<TinyStr4, Error> {
+        if self.is_empty() {
+            return Err(Error::InvalidSize { len: 0, max: 4 });
+        }
+        unsafe { Ok(TinyStr4::new_unchecked(self.0.to_le())) }
+    }
+
+    /// Converts the buffer into a `TinyStr4`, like `build`; the name reads
+    /// better when the buffer is used as a `TinyStr4Builder`.
+    #[inline(always)]
+    pub fn finish(self) -> Result<TinyStr4, Error> {
+        self.build()
+    }
+}
+
+impl fmt::Display for TinyStrBuf4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.deref())
+    }
+}
+
+impl fmt::Debug for TinyStrBuf4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.deref())
+    }
+}
+
+/// Lets `write!` append to the buffer; overflow, NUL and non-ASCII output
+/// fail with `fmt::Error`.
+impl fmt::Write for TinyStrBuf4 {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl Deref for TinyStrBuf4 {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        unsafe {
+            let slice = core::slice::from_raw_parts(&self.0 as *const _ as *const u8, self.len());
+            std::str::from_utf8_unchecked(slice)
+        }
+    }
+}
+
+impl From<TinyStr4> for TinyStrBuf4 {
+    fn from(input: TinyStr4) -> Self {
+        Self(u32::from_le(input.into()))
+    }
+}
+
+impl From<TinyStrBuf4> for TinyStr4 {
+    /// # Panics
+    ///
+    /// Panics if the buffer is empty; use `build` to handle that case.
+    fn from(input: TinyStrBuf4) -> Self {
+        input.build().expect("TinyStr can't be empty")
+    }
+}
+
+/// Collects chars into a `TinyStr4`, deferring any error to `try_build`.
+///
+/// Once a char fails to push, the collector ignores the rest of the input
+/// and `try_build` reports that first error.
+#[derive(Default, PartialEq, Eq, Debug)]
+pub struct TinyStr4Collector {
+    buf: TinyStrBuf4,
+    error: Option<Error>,
+}
+
+impl TinyStr4Collector {
+    /// Creates an empty collector.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buf: TinyStrBuf4::new(),
+            error: None,
+        }
+    }
+
+    /// Converts the collected chars into a `TinyStr4`.
+    ///
+    /// Returns the first error seen while collecting, or
+    /// `Err(Error::InvalidSize { .. })` if no chars were collected.
+    pub fn try_build(self) -> Result<TinyStr4, Error> {
+        match self.error {
+            Some(err) => Err(err),
+            None => self.buf.build(),
+        }
+    }
+}
+
+impl Extend<char> for TinyStr4Collector {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        if self.error.is_some() {
+            return;
+        }
+        for c in iter {
+            if let Err(err) = self.buf.push(c) {
+                self.error = Some(err);
+                return;
+            }
+        }
+    }
+}
+
+/// Lets `write!` feed the collector; once a char fails, writes return
+/// `fmt::Error` and `try_build` reports why.
+impl fmt::Write for TinyStr4Collector {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.chars());
+        match self.error {
+            Some(_) => Err(fmt::Error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromIterator<char> for TinyStr4Collector {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut collector = Self::new();
+        collector.extend(iter);
+        collector
+    }
+}
+
+impl FromIterator<char> for TinyStr4 {
+    /// # Panics
+    ///
+    /// Panics if the iterator yields no chars, more than 4 chars, or a
+    /// non-ASCII or NUL char; collect into a `TinyStr4Collector` and call
+    /// `try_build` to handle those cases.
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        iter.into_iter()
+            .collect::<TinyStr4Collector>()
+            .try_build()
+            .expect("chars don't form a valid TinyStr")
+    }
+}
@@ -0,0 +1,123 @@
+//! `zerovec` support: the unaligned forms of `TinyStr4`, `TinyStr8` and
+//! `TinyStr16`.
+
+// `usize::is_multiple_of` is newer than the Rust version `zerovec` itself
+// needs, so the length checks stay as `%`.
+#![allow(clippy::manual_is_multiple_of)]
+
+use std::convert::TryInto;
+
+use zerovec::ule::{AsULE, UleError, ULE};
+
+use crate::{TinyStr16, TinyStr4, TinyStr8};
+
+/// A `TinyStr4` as stored in a `ZeroVec`: its bytes followed by NUL
+/// padding, with no alignment requirement.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct TinyStr4ULE([u8; 4]);
+
+// Safety: `TinyStr4ULE` is a transparent byte array, so it has alignment 1
+// and no padding, and `validate_bytes` rejects slices that aren't a whole
+// number of valid, canonically padded strings.
+unsafe impl ULE for TinyStr4ULE {
+    fn validate_bytes(bytes: &[u8]) -> Result<(), UleError> {
+        if bytes.len() % 4 != 0 {
+            return Err(UleError::length::<Self>(bytes.len()));
+        }
+        for chunk in bytes.chunks_exact(4) {
+            TinyStr4::from_bytes_padded(chunk.try_into().unwrap())
+                .map_err(|_| UleError::parse::<Self>())?;
+        }
+        Ok(())
+    }
+}
+
+impl AsULE for TinyStr4 {
+    type ULE = TinyStr4ULE;
+
+    #[inline]
+    fn to_unaligned(self) -> TinyStr4ULE {
+        TinyStr4ULE(self.to_bytes())
+    }
+
+    #[inline]
+    fn from_unaligned(unaligned: TinyStr4ULE) -> Self {
+        // Every `TinyStr4ULE` comes from `to_unaligned` or was validated.
+        unsafe { Self::new_unchecked(u32::from_le_bytes(unaligned.0)) }
+    }
+}
+
+/// A `TinyStr8` as stored in a `ZeroVec`: its bytes followed by NUL
+/// padding, with no alignment requirement.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct TinyStr8ULE([u8; 8]);
+
+// Safety: `TinyStr8ULE` is a transparent byte array, so it has alignment 1
+// and no padding, and `validate_bytes` rejects slices that aren't a whole
+// number of valid, canonically padded strings.
+unsafe impl ULE for TinyStr8ULE {
+    fn validate_bytes(bytes: &[u8]) -> Result<(), UleError> {
+        if bytes.len() % 8 != 0 {
+            return Err(UleError::length::<Self>(bytes.len()));
+        }
+        for chunk in bytes.chunks_exact(8) {
+            TinyStr8::from_bytes_padded(chunk.try_into().unwrap())
+                .map_err(|_| UleError::parse::<Self>())?;
+        }
+        Ok(())
+    }
+}
+
+impl AsULE for TinyStr8 {
+    type ULE = TinyStr8ULE;
+
+    #[inline]
+    fn to_unaligned(self) -> TinyStr8ULE {
+        TinyStr8ULE(self.to_bytes())
+    }
+
+    #[inline]
+    fn from_unaligned(unaligned: TinyStr8ULE) -> Self {
+        // Every `TinyStr8ULE` comes from `to_unaligned` or was validated.
+        unsafe { Self::new_unchecked(u64::from_le_bytes(unaligned.0)) }
+    }
+}
+
+/// A `TinyStr16` as stored in a `ZeroVec`: its bytes followed by NUL
+/// padding, with no alignment requirement.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct TinyStr16ULE([u8; 16]);
+
+// Safety: `TinyStr16ULE` is a transparent byte array, so it has alignment 1
+// and no padding, and `validate_bytes` rejects slices that aren't a whole
+// number of valid, canonically padded strings.
+unsafe impl ULE for TinyStr16ULE {
+    fn validate_bytes(bytes: &[u8]) -> Result<(), UleError> {
+        if bytes.len() % 16 != 0 {
+            return Err(UleError::length::<Self>(bytes.len()));
+        }
+        for chunk in bytes.chunks_exact(16) {
+            TinyStr16::from_bytes_padded(chunk.try_into().unwrap())
+                .map_err(|_| UleError::parse::<Self>())?;
+        }
+        Ok(())
+    }
+}
+
+impl AsULE for TinyStr16 {
+    type ULE = TinyStr16ULE;
+
+    #[inline]
+    fn to_unaligned(self) -> TinyStr16ULE {
+        TinyStr16ULE(self.to_bytes())
+    }
+
+    #[inline]
+    fn from_unaligned(unaligned: TinyStr16ULE) -> Self {
+        // Every `TinyStr16ULE` comes from `to_unaligned` or was validated.
+        unsafe { Self::new_unchecked(u128::from_le_bytes(unaligned.0)) }
+    }
+}
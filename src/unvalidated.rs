@@ -0,0 +1,74 @@
+//! Unvalidated byte arrays for zero-copy parsing with the `zerocopy` crate.
+//!
+//! `TinyStr4`, `TinyStr8` and `TinyStr16` implement `zerocopy::AsBytes`:
+//! viewing one as bytes yields the string followed by NUL padding, exactly
+//! like `to_bytes`. They can't implement `FromBytes` or `Unaligned`, since
+//! not every byte pattern is a valid string (all-NUL, interior NULs and
+//! non-ASCII bytes are not) and the backing integers are aligned.
+//!
+//! Instead, the `TinyStr*Unvalidated` types here are plain byte arrays that
+//! implement `FromBytes`, `AsBytes` and `Unaligned`, so they can be cast
+//! from any byte buffer and then checked with `validate`.
+use zerocopy::{AsBytes, FromBytes, Unaligned};
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// The NUL-padded bytes of a possibly invalid `TinyStr4`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, FromBytes, AsBytes, Unaligned)]
+#[repr(transparent)]
+pub struct TinyStr4Unvalidated(pub [u8; 4]);
+
+impl TinyStr4Unvalidated {
+    /// Checks the bytes form a valid `TinyStr4`, as `from_bytes_padded`
+    /// does.
+    #[inline]
+    pub fn validate(self) -> Result<TinyStr4, Error> {
+        TinyStr4::from_bytes_padded(self.0)
+    }
+}
+
+impl From<TinyStr4> for TinyStr4Unvalidated {
+    fn from(input: TinyStr4) -> Self {
+        Self(input.to_bytes())
+    }
+}
+
+/// The NUL-padded bytes of a possibly invalid `TinyStr8`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, FromBytes, AsBytes, Unaligned)]
+#[repr(transparent)]
+pub struct TinyStr8Unvalidated(pub [u8; 8]);
+
+impl TinyStr8Unvalidated {
+    /// Checks the bytes form a valid `TinyStr8`, as `from_bytes_padded`
+    /// does.
+    #[inline]
+    pub fn validate(self) -> Result<TinyStr8, Error> {
+        TinyStr8::from_bytes_padded(self.0)
+    }
+}
+
+impl From<TinyStr8> for TinyStr8Unvalidated {
+    fn from(input: TinyStr8) -> Self {
+        Self(input.to_bytes())
+    }
+}
+
+/// The NUL-padded bytes of a possibly invalid `TinyStr16`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, FromBytes, AsBytes, Unaligned)]
+#[repr(transparent)]
+pub struct TinyStr16Unvalidated(pub [u8; 16]);
+
+impl TinyStr16Unvalidated {
+    /// Checks the bytes form a valid `TinyStr16`, as `from_bytes_padded`
+    /// does.
+    #[inline]
+    pub fn validate(self) -> Result<TinyStr16, Error> {
+        TinyStr16::from_bytes_padded(self.0)
+    }
+}
+
+impl From<TinyStr16> for TinyStr16Unvalidated {
+    fn from(input: TinyStr16) -> Self {
+        Self(input.to_bytes())
+    }
+}
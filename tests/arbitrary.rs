@@ -0,0 +1,88 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+use tinystr::{TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! check_invariants {
+    ($s:expr, $ty:ty, $int:ty, $consumed:expr, $available:expr) => {{
+        let s: $ty = $s;
+        prop_assert!(!s.is_empty());
+        prop_assert!(s.len() >= 1 && s.len() <= <$ty>::CAPACITY);
+        prop_assert!(s.as_str().is_ascii());
+        prop_assert!(!s.as_str().contains('\0'));
+        prop_assert_eq!(s.as_str().parse::<$ty>().unwrap(), s);
+        let raw = <$int>::from(s);
+        prop_assert_eq!(<$ty>::from_bytes_padded(raw.to_le_bytes()).unwrap(), s);
+        let lower = s.to_ascii_lowercase();
+        prop_assert_eq!(lower.to_ascii_uppercase().to_ascii_lowercase(), lower);
+        let (min, max) = <$ty as Arbitrary>::size_hint(0);
+        prop_assert!($consumed <= max.unwrap());
+        prop_assert!($available < min || $consumed >= min);
+    }};
+}
+
+proptest! {
+    #[test]
+    fn tiny4_arbitrary(bytes in proptest::collection::vec(any::<u8>(), 0..32)) {
+        let mut u = Unstructured::new(&bytes);
+        let s = TinyStr4::arbitrary(&mut u).unwrap();
+        check_invariants!(s, TinyStr4, u32, bytes.len() - u.len(), bytes.len());
+    }
+
+    #[test]
+    fn tiny8_arbitrary(bytes in proptest::collection::vec(any::<u8>(), 0..32)) {
+        let mut u = Unstructured::new(&bytes);
+        let s = TinyStr8::arbitrary(&mut u).unwrap();
+        check_invariants!(s, TinyStr8, u64, bytes.len() - u.len(), bytes.len());
+    }
+
+    #[test]
+    fn tiny16_arbitrary(bytes in proptest::collection::vec(any::<u8>(), 0..32)) {
+        let mut u = Unstructured::new(&bytes);
+        let s = TinyStr16::arbitrary(&mut u).unwrap();
+        check_invariants!(s, TinyStr16, u128, bytes.len() - u.len(), bytes.len());
+    }
+}
+
+// A fixed pseudo-random seed, so a regression reproduces exactly.
+fn seed_bytes() -> Vec<u8> {
+    let mut state: u32 = 0x2545_f491;
+    (0..4096)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+macro_rules! check_batch {
+    ($ty:ty) => {{
+        let bytes = seed_bytes();
+        let mut u = Unstructured::new(&bytes);
+        let mut count = 0;
+        while !u.is_empty() {
+            let s = <$ty>::arbitrary(&mut u).unwrap();
+            assert_eq!(s.as_str().parse::<$ty>(), Ok(s));
+            count += 1;
+        }
+        assert!(count >= bytes.len() / (1 + <$ty>::CAPACITY));
+    }};
+}
+
+#[test]
+fn tiny4_arbitrary_batch() {
+    check_batch!(TinyStr4);
+}
+
+#[test]
+fn tiny8_arbitrary_batch() {
+    check_batch!(TinyStr8);
+}
+
+#[test]
+fn tiny16_arbitrary_batch() {
+    check_batch!(TinyStr16);
+}
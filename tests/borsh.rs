@@ -0,0 +1,45 @@
+#![cfg(feature = "borsh")]
+
+use std::io::ErrorKind;
+
+use tinystr::{TinyStr16, TinyStr4, TinyStr8};
+
+#[test]
+fn tiny4_borsh() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let bytes = borsh::to_vec(&s).unwrap();
+    assert_eq!(bytes, b"en\0\0");
+    assert_eq!(borsh::from_slice::<TinyStr4>(&bytes).unwrap(), s);
+
+    let err = borsh::from_slice::<TinyStr4>(b"e\0n\0").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(borsh::from_slice::<TinyStr4>(b"\0\0\0\0").is_err());
+    assert!(borsh::from_slice::<TinyStr4>(b"en\xff\0").is_err());
+    assert!(borsh::from_slice::<TinyStr4>(b"en").is_err());
+    assert!(borsh::from_slice::<TinyStr4>(b"en\0\0\0").is_err());
+}
+
+#[test]
+fn tiny8_borsh() {
+    let strings: Vec<TinyStr8> = ["windows", "macos"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let bytes = borsh::to_vec(&strings).unwrap();
+    assert_eq!(&bytes[4..], b"windows\0macos\0\0\0");
+    assert_eq!(borsh::from_slice::<Vec<TinyStr8>>(&bytes).unwrap(), strings);
+
+    let err = borsh::from_slice::<TinyStr8>(b"win\0dows").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn tiny16_borsh() {
+    let s: TinyStr16 = "MacintoshOSX2019".parse().unwrap();
+    let bytes = borsh::to_vec(&s).unwrap();
+    assert_eq!(bytes, b"MacintoshOSX2019");
+    assert_eq!(borsh::from_slice::<TinyStr16>(&bytes).unwrap(), s);
+
+    let err = borsh::from_slice::<TinyStr16>(b"Macintosh\0OSX201").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
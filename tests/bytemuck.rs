@@ -0,0 +1,82 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::checked;
+use tinystr::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+#[test]
+fn tiny4_cast() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let raw: u32 = bytemuck::cast(s);
+    assert_eq!(raw, u32::from_le(u32::from(s)));
+    assert_eq!(checked::cast::<u32, TinyStr4>(raw), s);
+    assert_eq!(checked::try_cast_ref::<u32, TinyStr4>(&raw), Ok(&s));
+    assert!(checked::try_cast::<u32, TinyStr4>(0).is_err());
+    assert!(checked::try_cast::<u32, TinyStr4>(u32::from_le(0x0062_0061)).is_err());
+    assert!(checked::try_cast::<u32, TinyStr4>(u32::from_le(0x80)).is_err());
+
+    let strings: Vec<TinyStr4> = ["en", "fr", "zh"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let raw: &[u32] = bytemuck::cast_slice(&strings);
+    assert_eq!(checked::cast_slice::<u32, TinyStr4>(raw), &strings[..]);
+    assert_eq!(bytemuck::bytes_of(&strings[0]), b"en\0\0");
+}
+
+#[test]
+fn tiny8_cast() {
+    let s: TinyStr8 = "windows".parse().unwrap();
+    let raw: u64 = bytemuck::cast(s);
+    assert_eq!(checked::cast::<u64, TinyStr8>(raw), s);
+    assert!(checked::try_cast::<u64, TinyStr8>(0).is_err());
+    assert_eq!(bytemuck::bytes_of(&s), b"windows\0");
+    let raw = u64::from_ne_bytes(*b"macos\0\0\0");
+    assert_eq!(
+        checked::try_cast_ref::<u64, TinyStr8>(&raw),
+        Ok(&"macos".parse().unwrap())
+    );
+}
+
+#[test]
+fn tiny16_cast() {
+    let s: TinyStr16 = "metamorphosis".parse().unwrap();
+    let raw: u128 = bytemuck::cast(s);
+    assert_eq!(checked::cast::<u128, TinyStr16>(raw), s);
+    assert!(checked::try_cast::<u128, TinyStr16>(0).is_err());
+    assert!(checked::try_cast::<u128, TinyStr16>(u128::MAX).is_err());
+}
+
+#[test]
+fn tiny4_byte_array() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let bytes = s.to_byte_array();
+    assert_eq!(&bytes, b"en\0\0");
+    assert_eq!(bytes, s.to_bytes());
+    assert_eq!(TinyStr4::from_byte_array(bytes), Ok(s));
+    assert_eq!(
+        TinyStr4::from_byte_array(*b"e\0n\0"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert!(TinyStr4::from_byte_array([0; 4]).is_err());
+}
+
+#[test]
+fn tiny8_byte_array() {
+    let s: TinyStr8 = "windows".parse().unwrap();
+    let bytes = s.to_byte_array();
+    assert_eq!(&bytes, b"windows\0");
+    assert_eq!(TinyStr8::from_byte_array(bytes), Ok(s));
+    assert_eq!(
+        TinyStr8::from_byte_array(*b"windows\xff"),
+        Err(Error::NonAscii { index: 7 })
+    );
+}
+
+#[test]
+fn tiny16_byte_array() {
+    let s: TinyStr16 = "metamorphosis".parse().unwrap();
+    let bytes = s.to_byte_array();
+    assert_eq!(&bytes, b"metamorphosis\0\0\0");
+    assert_eq!(TinyStr16::from_byte_array(bytes), Ok(s));
+    assert!(TinyStr16::from_byte_array([0; 16]).is_err());
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "databake")]
+
+use databake::{Bake, BakeSize, CrateEnv};
+use tinystr::{TinyStr16, TinyStr4, TinyStr8};
+
+#[test]
+fn tiny4_bake() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let ctx = CrateEnv::default();
+    assert_eq!(
+        s.bake(&ctx).to_string(),
+        "unsafe { tinystr :: TinyStr4 :: new_unchecked (28261u32) }"
+    );
+    assert!(ctx.into_iter().eq(["tinystr"]));
+    assert_eq!(s.borrows_size(), 0);
+    // The baked expression, pasted in as source.
+    let baked = unsafe { tinystr::TinyStr4::new_unchecked(28261u32) };
+    assert_eq!(baked, s);
+}
+
+#[test]
+fn tiny8_bake() {
+    let s: TinyStr8 = "windows".parse().unwrap();
+    let ctx = CrateEnv::default();
+    assert_eq!(
+        s.bake(&ctx).to_string(),
+        format!(
+            "unsafe {{ tinystr :: TinyStr8 :: new_unchecked ({}u64) }}",
+            u64::from(s)
+        )
+    );
+    assert_eq!(unsafe { TinyStr8::new_unchecked(u64::from(s)) }, s);
+}
+
+#[test]
+fn tiny16_bake() {
+    let s: TinyStr16 = "MacintoshOSX2019".parse().unwrap();
+    let ctx = CrateEnv::default();
+    assert_eq!(
+        s.bake(&ctx).to_string(),
+        format!(
+            "unsafe {{ tinystr :: TinyStr16 :: new_unchecked ({}u128) }}",
+            u128::from(s)
+        )
+    );
+}
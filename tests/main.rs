@@ -1,6 +1,16 @@
-use std::fmt::Write;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write};
+use std::hash::{Hash, Hasher};
+use std::mem::size_of;
 use std::ops::Deref;
-use tinystr::{Error, TinyStr16, TinyStr4, TinyStr8};
+use tinystr::{
+    format_tiny, CaseInsensitive, Error, SubtagKind, TinyStr16, TinyStr16Builder,
+    TinyStr16Collector, TinyStr4, TinyStr4Builder, TinyStr4Collector, TinyStr8, TinyStr8Builder,
+    TinyStr8Collector, TinyStrBuf16, TinyStrBuf4, TinyStrBuf8,
+};
 
 #[test]
 fn tiny4_basic() {
@@ -10,18 +20,34 @@ fn tiny4_basic() {
 
 #[test]
 fn tiny4_size() {
-    assert_eq!("".parse::<TinyStr4>(), Err(Error::InvalidSize));
+    assert_eq!(
+        "".parse::<TinyStr4>(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
     assert!("1".parse::<TinyStr4>().is_ok());
     assert!("12".parse::<TinyStr4>().is_ok());
     assert!("123".parse::<TinyStr4>().is_ok());
     assert!("1234".parse::<TinyStr4>().is_ok());
-    assert_eq!("12345".parse::<TinyStr4>(), Err(Error::InvalidSize));
-    assert_eq!("123456789".parse::<TinyStr4>(), Err(Error::InvalidSize));
+    assert_eq!(
+        "12345".parse::<TinyStr4>(),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        "123456789".parse::<TinyStr4>(),
+        Err(Error::InvalidSize { len: 9, max: 4 })
+    );
 }
 
 #[test]
 fn tiny4_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr4>(), Err(Error::InvalidNull));
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr4>(),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        "ab\u{0}d".parse::<TinyStr4>(),
+        Err(Error::InvalidNull { index: 2 })
+    );
 }
 
 #[test]
@@ -35,7 +61,14 @@ fn tiny4_new_unchecked() {
 
 #[test]
 fn tiny4_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr4>(), Err(Error::NonAscii));
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr4>(),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr4::from_bytes_padded(*b"abc\xe4"),
+        Err(Error::NonAscii { index: 3 })
+    );
 }
 
 #[test]
@@ -127,266 +160,6425 @@ fn tiny4_debug() {
 }
 
 #[test]
-fn tiny8_basic() {
-    let s: TinyStr8 = "abcde".parse().unwrap();
-    assert_eq!(s.deref(), "abcde");
+fn tiny4_titlecase_words() {
+    assert_eq!(
+        "ab-c"
+            .parse::<TinyStr4>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "Ab-C"
+    );
+    assert_eq!(
+        "EN-U"
+            .parse::<TinyStr4>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "En-U"
+    );
+    assert_eq!(
+        "_ab"
+            .parse::<TinyStr4>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "_Ab"
+    );
 }
 
 #[test]
-fn tiny8_size() {
-    assert_eq!("".parse::<TinyStr8>(), Err(Error::InvalidSize));
-    assert!("1".parse::<TinyStr8>().is_ok());
-    assert!("12".parse::<TinyStr8>().is_ok());
-    assert!("123".parse::<TinyStr8>().is_ok());
-    assert!("1234".parse::<TinyStr8>().is_ok());
-    assert!("12345".parse::<TinyStr8>().is_ok());
-    assert!("123456".parse::<TinyStr8>().is_ok());
-    assert!("1234567".parse::<TinyStr8>().is_ok());
-    assert!("12345678".parse::<TinyStr8>().is_ok());
-    assert_eq!("123456789".parse::<TinyStr8>(), Err(Error::InvalidSize));
+fn tiny4_alphabetic() {
+    assert!("abYZ".parse::<TinyStr4>().unwrap().is_ascii_alphabetic());
+    assert!(!"abc1".parse::<TinyStr4>().unwrap().is_ascii_alphabetic());
+    assert!(!"@az[".parse::<TinyStr4>().unwrap().is_ascii_alphabetic());
+    assert!(!"`az{".parse::<TinyStr4>().unwrap().is_ascii_alphabetic());
+
+    assert!("abc1".parse::<TinyStr4>().unwrap().is_ascii_alphanumeric());
+    assert!("A123".parse::<TinyStr4>().unwrap().is_ascii_alphanumeric());
+    assert!("1234".parse::<TinyStr4>().unwrap().is_ascii_alphanumeric());
+    assert!(!"a/9:".parse::<TinyStr4>().unwrap().is_ascii_alphanumeric());
 }
 
 #[test]
-fn tiny8_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr8>(), Err(Error::InvalidNull));
+fn tiny4_len() {
+    assert_eq!(TinyStr4::CAPACITY, 4);
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr4::CAPACITY {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert_eq!(s.len(), len);
+        assert_eq!(s.len(), s.as_str().len());
+        assert_eq!(s.capacity(), 4);
+        assert!(s.len() <= s.capacity());
+        assert!(!s.is_empty());
+    }
 }
 
 #[test]
-fn tiny8_new_unchecked() {
-    let reference: TinyStr8 = "Windows".parse().unwrap();
-    let uval: u64 = reference.into();
-    let s = unsafe { TinyStr8::new_unchecked(uval) };
-    assert_eq!(s, reference);
-    assert_eq!(s, "Windows");
+fn tiny4_subtags() {
+    let valid = |s: &str| s.parse::<TinyStr4>().unwrap();
+
+    assert!(valid("en").is_language_subtag());
+    assert!(valid("yue").is_language_subtag());
+    assert!(valid("Hant").is_language_subtag());
+    assert!(!valid("419").is_language_subtag());
+    assert!(!valid("e").is_language_subtag());
+    assert!(!valid("e1").is_language_subtag());
+    assert!(!valid("41").is_language_subtag());
+    assert!(!valid("4190").is_language_subtag());
+    assert!(!valid("en-").is_language_subtag());
+
+    assert!(valid("US").is_region_subtag());
+    assert!(valid("419").is_region_subtag());
+    assert!(!valid("U").is_region_subtag());
+    assert!(!valid("USA").is_region_subtag());
+    assert!(!valid("41").is_region_subtag());
+    assert!(!valid("4a9").is_region_subtag());
+
+    assert!(valid("Hant").is_script_subtag());
+    assert!(valid("Latn").is_script_subtag());
+    assert!(valid("hant").is_script_subtag());
+    assert!(valid("HANT").is_script_subtag());
+    assert!(!valid("Han").is_script_subtag());
+    assert!(!valid("Han1").is_script_subtag());
 }
 
 #[test]
-fn tiny8_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr8>(), Err(Error::NonAscii));
+fn tiny4_as_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr4::CAPACITY {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert_eq!(s.as_bytes().len(), s.len());
+        assert_eq!(s.as_bytes(), s.as_str().as_bytes());
+        assert_eq!(AsRef::<[u8]>::as_ref(&s), &text.as_bytes()[..len]);
+    }
 }
 
 #[test]
-fn tiny8_alpha() {
-    let s: TinyStr8 = "@abcXYZ[".parse().unwrap();
-    assert!(!s.is_ascii_alphanumeric());
-    assert_eq!(s.to_ascii_uppercase().as_str(), "@ABCXYZ[");
-    assert_eq!(s.to_ascii_lowercase().as_str(), "@abcxyz[");
-
-    assert!("abcXYZ"
-        .parse::<TinyStr8>()
-        .unwrap()
-        .is_ascii_alphanumeric());
+fn tiny4_numeric() {
+    assert!("419".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!("0123".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!(!"4a9".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!(!"41.9".parse::<TinyStr4>().unwrap().is_ascii_numeric());
 }
 
 #[test]
-fn tiny8_titlecase() {
+fn tiny4_trim_ascii() {
+    let s: TinyStr4 = "  ab".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "ab");
+    assert_eq!(s.trim_ascii_end().unwrap(), s);
+    assert_eq!(s.trim_ascii().unwrap(), "ab");
+
+    let s: TinyStr4 = "ab  ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), s);
+    assert_eq!(s.trim_ascii_end().unwrap(), "ab");
+    assert_eq!(s.trim_ascii().unwrap(), "ab");
+
+    let s: TinyStr4 = " a  ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "a  ");
+    assert_eq!(s.trim_ascii_end().unwrap(), " a");
+    assert_eq!(s.trim_ascii().unwrap(), "a");
+
+    let s: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(s.trim_ascii().unwrap(), s);
+
+    let s: TinyStr4 = "    ".parse().unwrap();
     assert_eq!(
-        "abcdabcd"
-            .parse::<TinyStr8>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcd"
+        s.trim_ascii_start(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
     );
     assert_eq!(
-        "ABCDABCD"
-            .parse::<TinyStr8>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcd"
+        s.trim_ascii_end(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
     );
+    assert_eq!(s.trim_ascii(), Err(Error::InvalidSize { len: 0, max: 4 }));
+}
+
+#[test]
+fn tiny4_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr4::CAPACITY {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        let bytes = s.to_bytes();
+        assert_eq!(&bytes[..len], s.as_bytes());
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(bytes, u32::from(s).to_le_bytes());
+        assert_eq!(TinyStr4::from_bytes_padded(bytes), Ok(s));
+    }
+
+    let mut bytes = [0; 4];
     assert_eq!(
-        "aBCDaBCD"
-            .parse::<TinyStr8>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcd"
+        TinyStr4::from_bytes_padded(bytes),
+        Err(Error::InvalidSize { len: 0, max: 4 })
     );
+    bytes[0] = b'a';
+    bytes[2] = b'b';
     assert_eq!(
-        "A123a123"
-            .parse::<TinyStr8>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "A123a123"
+        TinyStr4::from_bytes_padded(bytes),
+        Err(Error::InvalidNull { index: 1 })
     );
+    bytes[1] = 0xe4;
     assert_eq!(
-        "123a123A"
-            .parse::<TinyStr8>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "123a123a"
+        TinyStr4::from_bytes_padded(bytes),
+        Err(Error::NonAscii { index: 1 })
     );
 }
 
 #[test]
-fn tiny8_ord() {
-    let mut v: Vec<TinyStr8> = vec!["nedis".parse().unwrap(), "macos".parse().unwrap()];
-    v.sort();
+fn tiny4_case() {
+    let s: TinyStr4 = "AB12".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
 
-    assert_eq!(v.get(0).unwrap().as_str(), "macos");
-    assert_eq!(v.get(1).unwrap().as_str(), "nedis");
+    let s: TinyStr4 = "ab12".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+
+    let s: TinyStr4 = "Abc".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_mixed_case());
+
+    let s: TinyStr4 = "12-@".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
 }
 
 #[test]
-fn tiny8_eq() {
-    let s1: TinyStr8 = "windows".parse().unwrap();
-    let s2: TinyStr8 = "mac".parse().unwrap();
-    let s3: TinyStr8 = "windows".parse().unwrap();
+fn tiny4_replace_char() {
+    let s: TinyStr4 = "en-u".parse().unwrap();
+    assert_eq!(s.replace_char('-', '_').unwrap(), "en_u");
+    assert_eq!(s.replace_char('e', 'E').unwrap(), "En-u");
+    assert_eq!(s.replace_char('$', '_').unwrap(), s);
+    assert_eq!(s.replace_char('\0', '_').unwrap(), s);
+    assert_eq!(s.replace_char('é', '_').unwrap(), s);
+    assert_eq!(s.replace_char('-', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(
+        s.replace_char('-', '\0'),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
 
-    assert_eq!(s1, s3);
-    assert_ne!(s1, s2);
+#[test]
+fn tiny4_bytes_iter() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr4::CAPACITY {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert_eq!(s.bytes().len(), len);
+        assert!(s.bytes().eq(text[..len].bytes()));
+        assert!(s.bytes().rev().eq(s.as_str().bytes().rev()));
+    }
+
+    let s: TinyStr4 = "abc".parse().unwrap();
+    let mut bytes = s.bytes();
+    assert_eq!(bytes.next(), Some(b'a'));
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes.next_back(), Some(b'c'));
+    assert_eq!(bytes.clone().next(), Some(b'b'));
+    assert_eq!(bytes.next_back(), Some(b'b'));
+    assert_eq!(bytes.next(), None);
+    assert_eq!(bytes.next_back(), None);
 }
 
 #[test]
-fn tiny8_display() {
-    let s: TinyStr8 = "abcdef".parse().unwrap();
-    let mut result = String::new();
-    write!(result, "{}", s).unwrap();
-    assert_eq!(result, "abcdef");
-    assert_eq!(format!("{}", s), "abcdef");
+fn tiny4_hexdigit() {
+    assert!("dEaF".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    assert!("12ff".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    assert!(!"xyz".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    assert!(!"12g4".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    assert!(!"12G4".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    assert!(!"@`/:".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
 }
 
 #[test]
-fn tiny8_debug() {
-    let s: TinyStr8 = "abcdef".parse().unwrap();
-    assert_eq!(format!("{:#?}", s), "\"abcdef\"");
+fn tiny4_swapcase() {
+    let s: TinyStr4 = "HeLo".parse().unwrap();
+    assert_eq!(s.to_ascii_swapcase(), "hElO");
+    assert_eq!(s.to_ascii_swapcase().to_ascii_swapcase(), s);
 }
 
 #[test]
-fn tiny16_size() {
-    assert_eq!("".parse::<TinyStr16>(), Err(Error::InvalidSize));
-    assert!("1".parse::<TinyStr16>().is_ok());
-    assert!("12".parse::<TinyStr16>().is_ok());
-    assert!("123".parse::<TinyStr16>().is_ok());
-    assert!("1234".parse::<TinyStr16>().is_ok());
-    assert!("12345".parse::<TinyStr16>().is_ok());
-    assert!("123456".parse::<TinyStr16>().is_ok());
-    assert!("1234567".parse::<TinyStr16>().is_ok());
-    assert!("12345678".parse::<TinyStr16>().is_ok());
-    assert!("123456781".parse::<TinyStr16>().is_ok());
-    assert!("1234567812".parse::<TinyStr16>().is_ok());
-    assert!("12345678123".parse::<TinyStr16>().is_ok());
-    assert!("123456781234".parse::<TinyStr16>().is_ok());
-    assert!("1234567812345".parse::<TinyStr16>().is_ok());
-    assert!("12345678123456".parse::<TinyStr16>().is_ok());
-    assert!("123456781234567".parse::<TinyStr16>().is_ok());
-    assert!("1234567812345678".parse::<TinyStr16>().is_ok());
+fn tiny4_graphic() {
     assert_eq!(
-        "12345678123456789".parse::<TinyStr16>(),
-        Err(Error::InvalidSize)
+        TinyStr4::from_str_graphic("a b~"),
+        Ok("a b~".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr4::from_str_graphic("a\tb"),
+        Err(Error::NonGraphic { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_graphic("ab\u{7f}"),
+        Err(Error::NonGraphic { index: 2 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_graphic("\u{1}"),
+        Err(Error::NonGraphic { index: 0 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_graphic("a\u{0}b"),
+        Err(Error::InvalidNull { index: 1 })
     );
+    assert!("a\tb".parse::<TinyStr4>().is_ok());
 }
 
 #[test]
-fn tiny16_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr16>(), Err(Error::InvalidNull));
+fn tiny4_chars() {
+    let text = "aB3-@[`{ ~z0Z9_.";
+    for len in 1..=TinyStr4::CAPACITY {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert!(s.chars().eq(text[..len].chars()));
+        assert!(s.chars().rev().eq(text[..len].chars().rev()));
+        assert_eq!(s.chars().len(), len);
+        assert_eq!(s.chars().size_hint(), (len, Some(len)));
+        let mut chars = s.chars();
+        chars.next();
+        assert_eq!(chars.size_hint(), (len - 1, Some(len - 1)));
+        assert!(chars.clone().eq(text[1..len].chars()));
+    }
 }
 
 #[test]
-fn tiny16_new_unchecked() {
-    let reference: TinyStr16 = "WindowsCE/ME/NT".parse().unwrap();
-    let uval: u128 = reference.into();
-    let s = unsafe { TinyStr16::new_unchecked(uval) };
-    assert_eq!(s, reference);
-    assert_eq!(s, "WindowsCE/ME/NT");
+fn tiny4_titlecase_all_words() {
+    let s: TinyStr4 = "aBCD".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), "Abcd");
+    let s: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), s.to_ascii_titlecase());
+    let s: TinyStr4 = " ab".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), " Ab");
+    let s: TinyStr4 = "ab ".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), "Ab ");
 }
 
 #[test]
-fn tiny16_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr16>(), Err(Error::NonAscii));
+fn tiny4_option_size() {
+    assert_eq!(size_of::<TinyStr4>(), 4);
+    assert_eq!(size_of::<Option<TinyStr4>>(), size_of::<TinyStr4>());
 }
 
 #[test]
-fn tiny16_alpha() {
-    let s: TinyStr16 = "@abcdefgTUVWXYZ[".parse().unwrap();
-    assert!(!s.is_ascii_alphanumeric());
-    assert_eq!(s.to_ascii_uppercase().as_str(), "@ABCDEFGTUVWXYZ[");
-    assert_eq!(s.to_ascii_lowercase().as_str(), "@abcdefgtuvwxyz[");
+fn tiny4_get() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(s.get(0), Some('e'));
+    assert_eq!(s.get(1), Some('n'));
+    assert_eq!(s.get(s.len() - 1), Some('n'));
+    assert_eq!(s.get(s.len()), None);
+    assert_eq!(s.get(usize::MAX), None);
+    assert_eq!(s.first(), 'e');
+    assert_eq!(s.last(), 'n');
+    for (i, c) in s.as_str().chars().enumerate() {
+        assert_eq!(s.get(i), Some(c));
+        assert!(s.get(i).unwrap().is_ascii());
+    }
 
-    assert!("abcdefgTUVWXYZ"
-        .parse::<TinyStr16>()
-        .unwrap()
-        .is_ascii_alphanumeric());
+    let s: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(s.get(4 - 1), Some('d'));
+    assert_eq!(s.get(4), None);
+    assert_eq!(s.last(), 'd');
+
+    let s: TinyStr4 = "x".parse().unwrap();
+    assert_eq!(s.first(), 'x');
+    assert_eq!(s.last(), 'x');
 }
 
 #[test]
-fn tiny16_titlecase() {
+fn tiny4_radix_fmt() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(format!("{:x}", s), "6e65");
+    assert_eq!(format!("{:X}", s), "6E65");
+    assert_eq!(format!("{:#010x}", s), "0x00006e65");
+    assert_eq!(format!("{:b}", s), "110111001100101");
+    assert_eq!(format!("{:o}", s), "67145");
+
+    let s: TinyStr4 = "Test".parse().unwrap();
+    let word = u32::from(s);
+    assert_eq!(format!("{:x}", s), format!("{:x}", word));
+    assert_eq!(format!("{:X}", s), format!("{:X}", word));
+    assert_eq!(format!("{:b}", s), format!("{:b}", word));
+    assert_eq!(format!("{:o}", s), format!("{:o}", word));
+}
+
+#[test]
+fn tiny4_index() {
+    let s: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(&s[1..3], "bc");
+    assert_eq!(&s[..2], "ab");
+    assert_eq!(&s[2..], "cd");
+    assert_eq!(&s[..], s.as_str());
+    assert_eq!(&s[4..], "");
+    assert_eq!(&s[1..1], "");
+}
+
+#[test]
+#[should_panic]
+fn tiny4_index_out_of_range() {
+    let s: TinyStr4 = "abc".parse().unwrap();
+    let _ = &s[1..4];
+}
+
+#[test]
+fn tiny4_starts_ends_with() {
+    let text = "abcd";
+    let s: TinyStr4 = text.parse().unwrap();
+    for len in 0..=4 {
+        assert!(s.starts_with(&text[..len]));
+        assert!(s.ends_with(&text[len..]));
+    }
+    for pat in &["", "a", "b", "ab", "ba", "cd", "abcdefghijklmnopq", "é"] {
+        assert_eq!(s.starts_with(pat), text.starts_with(pat));
+        assert_eq!(s.ends_with(pat), text.ends_with(pat));
+    }
+    assert!(s.starts_with(String::from("ab")));
+    assert!(s.starts_with_char('a'));
+    assert!(!s.starts_with_char('b'));
+    assert!(s.ends_with_char('d'));
+    assert!(!s.ends_with_char('a'));
+    assert!(!s.ends_with_char('é'));
+
+    let s: TinyStr4 = "x".parse().unwrap();
+    assert!(s.starts_with("x"));
+    assert!(s.ends_with("x"));
+    assert!(!s.starts_with("xy"));
+    assert!(!s.ends_with("yx"));
+    assert!(s.starts_with_char('x'));
+    assert!(s.ends_with_char('x'));
+}
+
+#[test]
+fn tiny4_from_str_lossy() {
+    assert_eq!(TinyStr4::from_str_lossy("en", b'?').unwrap(), "en");
+    assert_eq!(TinyStr4::from_str_lossy("aé", b'?').unwrap(), "a??");
+    assert_eq!(TinyStr4::from_str_lossy("\u{4000}", b'_').unwrap(), "___");
     assert_eq!(
-        "abcdabcdabcdabcd"
-            .parse::<TinyStr16>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcdabcdabcd"
+        TinyStr4::from_str_lossy("", b'?'),
+        Err(Error::InvalidSize { len: 0, max: 4 })
     );
     assert_eq!(
-        "ABCDABCDABCDABCD"
-            .parse::<TinyStr16>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcdabcdabcd"
+        TinyStr4::from_str_lossy("abcdé", b'?'),
+        Err(Error::InvalidSize { len: 6, max: 4 })
     );
     assert_eq!(
-        "aBCDaBCDaBCDaBCD"
-            .parse::<TinyStr16>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "Abcdabcdabcdabcd"
+        TinyStr4::from_str_lossy("a\u{0}é", b'?'),
+        Err(Error::InvalidNull { index: 1 })
     );
     assert_eq!(
-        "A123a123A123a123"
-            .parse::<TinyStr16>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "A123a123a123a123"
+        TinyStr4::from_str_lossy("é", 0),
+        Err(Error::InvalidNull { index: 0 })
     );
     assert_eq!(
-        "123a123A123a123A"
-            .parse::<TinyStr16>()
-            .unwrap()
-            .to_ascii_titlecase()
-            .as_str(),
-        "123a123a123a123a"
+        TinyStr4::from_str_lossy("é", 0xbf),
+        Err(Error::NonAscii { index: 0 })
     );
 }
 
 #[test]
-fn tiny16_ord() {
-    let mut v: Vec<TinyStr16> = vec!["nedis_xxxx".parse().unwrap(), "macos_xxxx".parse().unwrap()];
-    v.sort();
-
-    assert_eq!(v.get(0).unwrap().as_str(), "macos_xxxx");
-    assert_eq!(v.get(1).unwrap().as_str(), "nedis_xxxx");
+fn tiny4_repeat_char() {
+    assert_eq!(TinyStr4::repeat_char('x', 1).unwrap(), "x");
+    assert_eq!(TinyStr4::repeat_char('x', 3).unwrap(), "xxx");
+    assert_eq!(
+        TinyStr4::repeat_char('-', 4).unwrap(),
+        "-".repeat(4).as_str()
+    );
+    assert_eq!(
+        TinyStr4::repeat_char('x', 0),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::repeat_char('x', 5),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::repeat_char('é', 2),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr4::repeat_char('\0', 2),
+        Err(Error::InvalidNull { index: 0 })
+    );
 }
 
 #[test]
-fn tiny16_eq() {
-    let s1: TinyStr16 = "windows98SE".parse().unwrap();
-    let s2: TinyStr16 = "mac".parse().unwrap();
-    let s3: TinyStr16 = "windows98SE".parse().unwrap();
-
-    assert_eq!(s1, s3);
-    assert_ne!(s1, s2);
-}
+fn tiny4_strip_prefix_suffix() {
+    let s: TinyStr4 = "x-ab".parse().unwrap();
+    assert_eq!(s.strip_prefix("x-").unwrap(), "ab");
+    assert_eq!(s.strip_prefix("").unwrap(), s);
+    assert_eq!(s.strip_prefix("y-"), None);
+    assert_eq!(s.strip_prefix("x-p"), None);
+    assert_eq!(s.strip_prefix(s.as_str()), None);
+    assert_eq!(s.strip_prefix("x-abc"), None);
 
-#[test]
-fn tiny16_display() {
-    let s: TinyStr16 = "abcdefghijkl".parse().unwrap();
-    let mut result = String::new();
-    write!(result, "{}", s).unwrap();
-    assert_eq!(result, "abcdefghijkl");
-    assert_eq!(format!("{}", s), "abcdefghijkl");
+    assert_eq!(s.strip_suffix("b").unwrap(), "x-a");
+    assert_eq!(s.strip_suffix("").unwrap(), s);
+    assert_eq!(s.strip_suffix("x"), None);
+    assert_eq!(s.strip_suffix(s.as_str()), None);
+    assert_eq!(s.strip_suffix("zx-ab"), None);
 }
 
 #[test]
-fn tiny16_debug() {
+fn tiny4_from_str_truncating() {
+    let text = "abcd";
+    assert_eq!(TinyStr4::from_str_truncating(text).unwrap(), text);
+    assert_eq!(TinyStr4::from_str_truncating("abcde").unwrap(), text);
+    assert_eq!(TinyStr4::from_str_truncating("abcdefghijk").unwrap(), text);
+    assert_eq!(TinyStr4::from_str_truncating("french").unwrap(), "fren");
+    assert_eq!(TinyStr4::from_str_truncating("abcdé").unwrap(), text);
+    assert_eq!(
+        TinyStr4::from_str_truncating("ab"),
+        Ok("ab".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr4::from_str_truncating(""),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_truncating("aécd"),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_truncating("a\u{0}cd"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny4_contains() {
+    let alphabet = ["", "a", "b", "-", "é"];
+    let mut needles: Vec<String> = vec![];
+    for x in &alphabet {
+        for y in &alphabet {
+            for z in &alphabet {
+                needles.push(format!("{}{}{}", x, y, z));
+            }
+        }
+    }
+    for text in &["a", "ab", "ba", "a-b", "abab"] {
+        let s: TinyStr4 = text.parse().unwrap();
+        for needle in &needles {
+            assert_eq!(s.contains(needle), text.contains(needle.as_str()));
+        }
+        for i in 0..text.len() {
+            for j in i..=text.len() {
+                assert!(s.contains(&text[i..j]));
+            }
+        }
+        assert!(!s.contains(&format!("{}a", text)));
+    }
+}
+
+#[test]
+fn tiny4_replace() {
+    let s: TinyStr4 = "en_u".parse().unwrap();
+    assert_eq!(s.replace('_', '-').unwrap(), "en-u");
+    assert_eq!(s.replace('#', '-').unwrap(), s);
+    assert_eq!(s.replace('\0', '-').unwrap(), s);
+    assert_eq!(s.replace('é', '-'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(s.replace('_', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.replace('_', '\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny4_buf() {
+    let text = "abcdefghijklmnop";
+    let mut buf = TinyStrBuf4::new();
+    assert!(buf.is_empty());
+    assert_eq!(buf.pop(), None);
+    assert_eq!(buf.build(), Err(Error::InvalidSize { len: 0, max: 4 }));
+    for (i, c) in text[..4].chars().enumerate() {
+        buf.push(c).unwrap();
+        assert_eq!(buf.len(), i + 1);
+        assert_eq!(&*buf, &text[..=i]);
+    }
+    assert_eq!(buf.push('x'), Err(Error::InvalidSize { len: 5, max: 4 }));
+    assert_eq!(buf.as_str(), &text[..4]);
+    assert_eq!(buf.build().unwrap(), &text[..4]);
+
+    assert_eq!(buf.pop(), Some('d'));
+    assert_eq!(format!("{}", buf), &text[..3]);
+    assert_eq!(format!("{:?}", buf), format!("{:?}", &text[..3]));
+    buf.clear();
+    assert!(buf.is_empty());
+    assert_eq!(buf.push('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(buf.push('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert!(buf.is_empty());
+
+    let s: TinyStr4 = "en".parse().unwrap();
+    let mut buf = TinyStrBuf4::from(s);
+    assert_eq!(buf.len(), 2);
+    buf.push('-').unwrap();
+    assert_eq!(TinyStr4::from(buf), "en-");
+    assert_eq!(buf.pop(), Some('-'));
+    assert_eq!(TinyStr4::from(buf), s);
+}
+
+#[test]
+fn tiny4_find() {
+    let text = "a-b-";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for c in &['a', 'b', '-', 'z', '\0', 'é', '\u{80}'] {
+            assert_eq!(s.find(*c), text[..len].find(*c));
+            assert_eq!(s.rfind(*c), text[..len].rfind(*c));
+        }
+    }
+    let s: TinyStr4 = "x".parse().unwrap();
+    assert_eq!(s.find('x'), Some(0));
+    assert_eq!(s.rfind('x'), Some(0));
+}
+
+#[test]
+fn tiny4_byte_at() {
+    let s: TinyStr4 = "a-Z9".parse().unwrap();
+    for i in 0..s.len() {
+        assert_eq!(s.byte_at(i), Some(s.as_bytes()[i]));
+        assert_eq!(s.get(i), s.as_str().chars().nth(i));
+    }
+    assert_eq!(s.byte_at(s.len()), None);
+    assert_eq!(s.get(s.len()), None);
+}
+
+#[test]
+fn tiny4_contains_char() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for c in "\0 -_aehmpqzA~é\u{80}\u{7f}\u{4000}".chars() {
+            assert_eq!(
+                s.contains_char(c),
+                s.as_str().contains(c),
+                "{:?} {:?}",
+                s,
+                c
+            );
+        }
+        for b in 0..=255u8 {
+            assert_eq!(
+                s.contains_byte(b),
+                s.as_bytes().contains(&b),
+                "{:?} {}",
+                s,
+                b
+            );
+        }
+    }
+    let s: TinyStr4 = "a-b".parse().unwrap();
+    assert!(s.contains_char('-'));
+    assert!(!s.contains_char('_'));
+}
+
+#[test]
+fn tiny4_from_iter() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr4 = text[..4].chars().collect();
+    assert_eq!(s, &text[..4]);
+    let s: TinyStr4 = "the quick fox"
+        .split(' ')
+        .filter_map(|w| w.chars().next())
+        .collect();
+    assert_eq!(s, "tqf");
+
+    let c: TinyStr4Collector = text[..4].chars().collect();
+    assert_eq!(c.try_build().unwrap(), &text[..4]);
+    let c: TinyStr4Collector = text[..=4].chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidSize { len: 5, max: 4 }));
+    let c: TinyStr4Collector = "aébc".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 1 }));
+    let c: TinyStr4Collector = "a\0".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidNull { index: 1 }));
+    assert_eq!(
+        TinyStr4Collector::new().try_build(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+
+    let mut c = TinyStr4Collector::new();
+    c.extend("ab".chars());
+    c.extend("é".chars());
+    c.extend("c".chars());
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 2 }));
+}
+
+#[test]
+#[should_panic]
+fn tiny4_from_iter_overflow() {
+    let _: TinyStr4 = "abcdefghijklmnopq"[..=4].chars().collect();
+}
+
+#[test]
+fn tiny4_from_str_lossy_truncating() {
+    assert_eq!(
+        TinyStr4::from_str_lossy_truncating("caféX").unwrap(),
+        "caf?"
+    );
+    assert_eq!(TinyStr4::from_str_lossy_truncating("éa").unwrap(), "??a");
+    assert_eq!(TinyStr4::from_str_lossy_truncating("a\0b").unwrap(), "a?b");
+    assert_eq!(TinyStr4::from_str_lossy_truncating("\0").unwrap(), "?");
+    assert_eq!(TinyStr4::from_str_lossy_truncating("en").unwrap(), "en");
+    assert_eq!(TinyStr4::from_str_lossy_truncating(""), None);
+}
+
+#[test]
+fn tiny4_split() {
+    for text in &[
+        "a", "-", "a-b", "-a", "a-", "--", "a--b", "-ab-", "ab-c", "a-bc",
+    ] {
+        let s: TinyStr4 = text.parse().unwrap();
+        let expected: Vec<&str> = text.split('-').filter(|seg| !seg.is_empty()).collect();
+        assert_eq!(s.split('-').collect::<Vec<_>>(), expected, "{:?}", text);
+    }
+    let s: TinyStr4 = "a-b".parse().unwrap();
+    assert_eq!(s.split('_').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('é').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('\0').collect::<Vec<_>>(), vec![s]);
+    let mut it = s.split('-');
+    assert_eq!(it.next().unwrap(), "a");
+    assert_eq!(it.next().unwrap(), "b");
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn tiny4_hash() {
+    let text = "en";
+    let s: TinyStr4 = text.parse().unwrap();
+    let mut h1 = DefaultHasher::new();
+    text.hash(&mut h1);
+    let mut h2 = DefaultHasher::new();
+    s.hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
+
+    let mut map = HashMap::new();
+    map.insert(s, 1);
+    assert_eq!(map.get(text), Some(&1));
+    assert_eq!(map.get("other"), None);
+}
+
+#[test]
+fn tiny4_split_once() {
+    let s: TinyStr4 = "en-U".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "en");
+    assert_eq!(tail, "U");
+    assert_eq!(s.split_once('_'), None);
+    assert_eq!(s.split_once('é'), None);
+    let s: TinyStr4 = "-en".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr4 = "en-".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr4 = "a-b-".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "a");
+    assert_eq!(tail, "b-");
+}
+
+#[test]
+fn tiny4_split_at() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr4 = text[..4].parse().unwrap();
+    for idx in 1..4 {
+        let (head, tail) = s.split_at(idx);
+        assert_eq!(head, &text[..idx]);
+        assert_eq!(tail.unwrap(), &text[idx..4]);
+    }
+    assert_eq!(s.split_at(4), (s, None));
+    let s: TinyStr4 = "ab".parse().unwrap();
+    assert_eq!(s.split_at(2), (s, None));
+}
+
+#[test]
+#[should_panic]
+fn tiny4_split_at_zero() {
+    let s: TinyStr4 = "ab".parse().unwrap();
+    s.split_at(0);
+}
+
+#[test]
+#[should_panic]
+fn tiny4_split_at_out_of_bounds() {
+    let s: TinyStr4 = "ab".parse().unwrap();
+    s.split_at(3);
+}
+
+#[test]
+fn tiny4_replace_matches_str() {
+    let text = "a-b_c-de--f_gh-i";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for &(from, to) in &[('-', '_'), ('_', '-'), ('a', 'A'), ('-', '-'), ('z', 'y')] {
+            let expected = text[..len].replace(from, to.encode_utf8(&mut [0; 4]));
+            assert_eq!(s.replace(from, to).unwrap(), expected.as_str());
+            assert_eq!(s.replace(from, to).unwrap().len(), len);
+        }
+    }
+}
+
+#[test]
+fn tiny4_into_string() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        let owned = String::from(s);
+        assert_eq!(owned, &text[..len]);
+        assert_eq!(owned.len(), len);
+        assert_eq!(owned, s.to_string());
+    }
+    let v: Vec<String> = vec!["en".parse::<TinyStr4>().unwrap()]
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    assert_eq!(v, vec!["en"]);
+}
+
+#[test]
+fn tiny4_cow() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    match Cow::from(&s) {
+        Cow::Borrowed(b) => assert_eq!(b, "en"),
+        Cow::Owned(_) => panic!("expected a borrowed Cow"),
+    }
+    match Cow::from(s) {
+        Cow::Owned(o) => assert_eq!(o, "en"),
+        Cow::Borrowed(_) => panic!("expected an owned Cow"),
+    }
+    let takes_cow = |c: Cow<str>| c.len();
+    assert_eq!(takes_cow((&s).into()), s.len());
+    assert_eq!(takes_cow(s.into()), s.len());
+}
+
+#[test]
+fn tiny4_reverse() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        let expected: String = text[..len].chars().rev().collect();
+        assert_eq!(s.reverse(), expected.as_str());
+        assert_eq!(s.reverse().len(), len);
+        assert_eq!(s.reverse().reverse(), s);
+    }
+    let s: TinyStr4 = "x".parse().unwrap();
+    assert_eq!(s.reverse(), s);
+    let s: TinyStr4 = "n-US".parse().unwrap();
+    assert_eq!(s.reverse(), "SU-n");
+}
+
+#[test]
+fn tiny4_try_from_chars() {
+    assert_eq!(
+        TinyStr4::try_from_chars(['e', 'n'].iter().copied()).unwrap(),
+        "en"
+    );
+    assert_eq!(
+        TinyStr4::try_from_chars("abcdefghijklmnop"[..4].chars()).unwrap(),
+        &"abcdefghijklmnop"[..4]
+    );
+    assert_eq!(
+        TinyStr4::try_from_chars("abcdefghijklmnopq"[..=4].chars()),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::try_from_chars("aé".chars()),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::try_from_chars(std::iter::empty()),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+}
+
+#[test]
+fn tiny4_repeat() {
+    let s: TinyStr4 = "ab".parse().unwrap();
+    assert_eq!(s.repeat(1).unwrap(), s);
+    assert_eq!(s.repeat(2).unwrap(), "ab".repeat(2).as_str());
+    assert_eq!(s.repeat(3), Err(Error::InvalidSize { len: 6, max: 4 }));
+    assert_eq!(s.repeat(0), Err(Error::InvalidSize { len: 0, max: 4 }));
+    assert_eq!(
+        s.repeat(usize::MAX),
+        Err(Error::InvalidSize {
+            len: usize::MAX,
+            max: 4
+        })
+    );
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for n in 1..=4 / len {
+            assert_eq!(s.repeat(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat(4 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny4_repeat8() {
+    let s: TinyStr4 = "ab".parse().unwrap();
+    assert_eq!(s.repeat8(1), Ok(TinyStr8::from(s)));
+    assert_eq!(s.repeat8(4).unwrap(), "ab".repeat(4).as_str());
+    assert_eq!(s.repeat8(5), Err(Error::InvalidSize { len: 10, max: 8 }));
+    assert_eq!(s.repeat8(0), Err(Error::InvalidSize { len: 0, max: 8 }));
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for n in 1..=8 / len {
+            assert_eq!(s.repeat8(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat8(8 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny4_repeat16() {
+    let s: TinyStr4 = "ab".parse().unwrap();
+    assert_eq!(s.repeat16(1), Ok(TinyStr16::from(s)));
+    assert_eq!(s.repeat16(8).unwrap(), "ab".repeat(8).as_str());
+    assert_eq!(s.repeat16(9), Err(Error::InvalidSize { len: 18, max: 16 }));
+    assert_eq!(s.repeat16(0), Err(Error::InvalidSize { len: 0, max: 16 }));
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        for n in 1..=16 / len {
+            assert_eq!(s.repeat16(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat16(16 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny4_first_last_match_chars() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert_eq!(Some(s.first()), s.as_str().chars().next());
+        assert_eq!(Some(s.last()), s.as_str().chars().last());
+        assert_eq!(s.first() == s.last(), len == 1);
+    }
+}
+
+#[test]
+fn tiny4_concat() {
+    let text = "abcdefghijklmnop";
+    for len in 1..4 {
+        let a: TinyStr4 = text[..len].parse().unwrap();
+        let b: TinyStr4 = text[len..4].parse().unwrap();
+        let s = a.try_concat(b).unwrap();
+        assert_eq!(s, &text[..4]);
+        assert_eq!(s.len(), 4);
+        assert_eq!(&*s, &text[..4]);
+        assert_eq!(a + b, s);
+        let parsed: TinyStr4 = text[..4].parse().unwrap();
+        assert_eq!(s.cmp(&parsed), Ordering::Equal);
+        assert_eq!(s.cmp(&a), Ordering::Greater);
+    }
+    let en: TinyStr4 = "en".parse().unwrap();
+    let dash: TinyStr4 = "-".parse().unwrap();
+    assert_eq!((en + dash).try_concat(dash).unwrap(), "en--");
+    let full: TinyStr4 = text[..4].parse().unwrap();
+    assert_eq!(
+        full.try_concat(dash),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny4_add_overflow() {
+    let full: TinyStr4 = "abcdefghijklmnop"[..4].parse().unwrap();
+    let _ = full + full;
+}
+
+#[test]
+fn tiny4_concat_widening() {
+    let text = "abcdefghijklmnop";
+    for i in 1..=4 {
+        for j in 1..=4 {
+            let a: TinyStr4 = text[..i].parse().unwrap();
+            let b: TinyStr4 = text[i..i + j].parse().unwrap();
+            let s = a.concat(b);
+            assert_eq!(s, &text[..i + j]);
+            assert_eq!(s.len(), i + j);
+            assert_eq!(s.cmp(&text[..i + j].parse().unwrap()), Ordering::Equal);
+        }
+    }
+}
+
+#[test]
+fn tiny4_display_format() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(format!("{:>8}", s), "      en");
+    assert_eq!(format!("{:<6}|", s), "en    |");
+    assert_eq!(format!("{:*^6}", s), "**en**");
+    assert_eq!(format!("{:.1}", s), "e");
+    assert_eq!(format!("{:>4.1}", s), "   e");
+    let buf = TinyStrBuf4::from(s);
+    assert_eq!(format!("{:-<5}", buf), "en---");
+}
+
+#[test]
+fn tiny4_strip_char() {
+    let s: TinyStr4 = "-en-".parse().unwrap();
+    assert_eq!(s.strip_prefix_char('-'), Some("en-"));
+    assert_eq!(s.strip_suffix_char('-'), Some("-en"));
+    assert_eq!(s.strip_prefix_char('e'), None);
+    assert_eq!(s.strip_suffix_char('n'), None);
+    assert_eq!(s.strip_prefix_char('é'), None);
+    assert_eq!(s.strip_prefix_str("-"), Some("en-"));
+    assert_eq!(s.strip_prefix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("en"), None);
+    let x: TinyStr4 = "x".parse().unwrap();
+    assert_eq!(x.strip_prefix_char('x'), Some(""));
+    assert_eq!(x.strip_suffix_char('x'), Some(""));
+    assert_eq!(x.strip_prefix_char('y'), None);
+}
+
+#[test]
+fn tiny4_as_raw_hex() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(s.as_raw_hex(), "0x00006e65");
+    assert_eq!(s.as_raw_hex().len(), 2 + 2 * 4);
+    assert_eq!(format!("{:#?}", s), "\"en\"");
+}
+
+#[test]
+fn tiny4_try_push() {
+    let text = "abcdefghijklmnop";
+    let mut s: TinyStr4 = "a".parse().unwrap();
+    for len in 2..=4 {
+        s = s.try_push(text.as_bytes()[len - 1] as char).unwrap();
+        assert_eq!(s, &text[..len]);
+    }
+    assert_eq!(s.try_push('x'), Err(Error::InvalidSize { len: 5, max: 4 }));
+    let s: TinyStr4 = "ab".parse().unwrap();
+    assert_eq!(s.try_push('é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\u{4000}'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny4_try_push_str() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr4 = "a".parse().unwrap();
+    assert_eq!(s.try_push_str(&text[1..4]).unwrap(), &text[..4]);
+    assert_eq!(s.try_push_str("").unwrap(), s);
+    assert_eq!(
+        s.try_push_str(&text[1..=4]),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        s.try_push_str(text),
+        Err(Error::InvalidSize { len: 18, max: 4 })
+    );
+    let full: TinyStr4 = text[..4].parse().unwrap();
+    assert_eq!(
+        full.try_push_str("x"),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(s.try_push_str("é"), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(s.try_push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny4_concat4() {
+    let p = |s: &str| s.parse::<TinyStr4>().unwrap();
+    let s = tinystr::concat4(p("zh"), p("Hant")).unwrap();
+    assert_eq!(s, "zhHant");
+    assert_eq!(&*s, "zhHant");
+    assert_eq!(s.len(), 6);
+    assert_eq!(tinystr::concat4(p("a"), p("b")).unwrap(), "ab");
+    assert_eq!(tinystr::concat4(p("abcd"), p("efgh")).unwrap(), "abcdefgh");
+}
+
+#[test]
+fn tiny4_byte_layout() {
+    // These hold on every target; CI also runs them on big-endian s390x.
+    let fr: TinyStr4 = "fr".parse().unwrap();
+    let zh: TinyStr4 = "zh".parse().unwrap();
+    assert!(fr < zh);
+    assert!("a".parse::<TinyStr4>().unwrap() < "aa".parse::<TinyStr4>().unwrap());
+    assert!("aa".parse::<TinyStr4>().unwrap() < "b".parse::<TinyStr4>().unwrap());
+    assert_eq!(fr.to_bytes()[..3], *b"fr\0");
+    assert_eq!(u32::from(fr), 0x7266);
+    assert_eq!(unsafe { TinyStr4::new_unchecked(0x7266) }, "fr");
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        assert_eq!(s.deref().len(), len);
+        assert_eq!(s.to_string(), &text[..len]);
+        assert_eq!(
+            s.to_ascii_uppercase(),
+            text[..len].to_ascii_uppercase().as_str()
+        );
+        assert_eq!(s.last(), text.as_bytes()[len - 1] as char);
+    }
+}
+
+#[test]
+fn tiny4_buf_write() {
+    let (prefix, idx, long, e_acute) = ("x", 7, "abcdefghijklmnop", 'é');
+    let mut buf = TinyStrBuf4::new();
+    assert_eq!(buf.remaining_capacity(), 4);
+    write!(buf, "{}{:02}", prefix, idx).unwrap();
+    assert_eq!(buf.as_str(), "x07");
+    assert_eq!(buf.remaining_capacity(), 1);
+    buf.push_str("a").unwrap();
+    assert_eq!(buf.remaining_capacity(), 0);
+    assert_eq!(buf.build().unwrap(), "x07a");
+    assert!(write!(buf, "y").is_err());
+
+    let mut buf = TinyStrBuf4::new();
+    assert!(write!(buf, "ab-{}", long).is_err());
+    assert_eq!(buf.as_str(), "ab-");
+    let mut buf = TinyStrBuf4::new();
+    assert!(write!(buf, "a{}", e_acute).is_err());
+    assert_eq!(buf.as_str(), "a");
+    assert_eq!(buf.push_str("bé"), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(buf.push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+    assert_eq!(
+        buf.push_str("abcdefghijklmnop"),
+        Err(Error::InvalidSize { len: 17, max: 4 })
+    );
+    assert_eq!(buf.as_str(), "a");
+}
+
+#[test]
+fn tiny4_builder() {
+    struct Accented;
+    impl fmt::Display for Accented {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("é")
+        }
+    }
+
+    let prefix = "ab";
+    let mut builder = TinyStr4Builder::new();
+    write!(builder, "{}{:02}", prefix, 7).unwrap();
+    assert_eq!(builder.remaining_capacity(), 0);
+    assert_eq!(builder.finish(), Ok("ab07".parse::<TinyStr4>().unwrap()));
+
+    let mut builder = TinyStr4Builder::new();
+    assert!(write!(builder, "{}{:02}", prefix, 700).is_err());
+    assert_eq!(builder.as_str(), "ab");
+
+    let mut builder = TinyStr4Builder::new();
+    builder.push('a').unwrap();
+    assert!(write!(builder, "{}", Accented).is_err());
+    assert_eq!(builder.finish(), Ok("a".parse::<TinyStr4>().unwrap()));
+
+    assert_eq!(
+        TinyStr4Builder::new().finish(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+}
+
+#[test]
+fn tiny4_count_char() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr4 = text[..4].parse().unwrap();
+    for c in text[..4].chars() {
+        assert_eq!(s.count_char(c), 1);
+    }
+    assert_eq!(s.count_char('z'), 0);
+    assert_eq!(s.count_char('é'), 0);
+    assert_eq!(s.count_char('\0'), 0);
+    let s: TinyStr4 = "a-a-".parse().unwrap();
+    assert_eq!(s.count_char('a'), s.as_str().matches('a').count());
+    assert_eq!(s.count_char('-'), s.as_str().matches('-').count());
+    let s: TinyStr4 = "x".repeat(4).parse().unwrap();
+    assert_eq!(s.count_char('x'), 4);
+}
+
+#[test]
+fn tiny4_parse_rejects_every_position() {
+    for len in 1..=4 {
+        for pos in 0..len {
+            let mut bytes = vec![b'a'; len];
+            bytes[pos] = 0;
+            let text = String::from_utf8(bytes).unwrap();
+            assert_eq!(
+                text.parse::<TinyStr4>(),
+                Err(Error::InvalidNull { index: pos })
+            );
+            if pos + 1 < len {
+                let text = format!("{}é{}", "a".repeat(pos), "a".repeat(len - pos - 2));
+                assert_eq!(
+                    text.parse::<TinyStr4>(),
+                    Err(Error::NonAscii { index: pos })
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tiny4_from_display() {
+    struct Repeat(usize);
+
+    impl fmt::Display for Repeat {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for _ in 0..self.0 {
+                f.write_str("ab")?;
+            }
+            Ok(())
+        }
+    }
+
+    assert_eq!(TinyStr4::from_display(&42).unwrap(), "42");
+    assert_eq!(
+        TinyStr4::from_display(&Repeat(2)).unwrap(),
+        "ab".repeat(2).as_str()
+    );
+    assert_eq!(
+        TinyStr4::from_display(&Repeat(3)),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::from_display(&'é'),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr4::from_display(&""),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+
+    let idx = 7;
+    assert_eq!(format_tiny!(TinyStr4, "x{:03}", idx).unwrap(), "x007");
+    assert_eq!(
+        format_tiny!(TinyStr4, "{:>4}", idx).unwrap(),
+        format!("{:>4}", idx).as_str()
+    );
+    assert_eq!(
+        format_tiny!(TinyStr4, "{:>5}", idx),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        format_tiny!(TinyStr4, "a{}", "\0"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny4_is_palindrome() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(s.reverse(), "ne");
+    assert!(!s.is_palindrome());
+    assert!("x".parse::<TinyStr4>().unwrap().is_palindrome());
+    assert!("aa".parse::<TinyStr4>().unwrap().is_palindrome());
+    assert!("aba".parse::<TinyStr4>().unwrap().is_palindrome());
+    assert!("abba".parse::<TinyStr4>().unwrap().is_palindrome());
+    assert!(!"abca".parse::<TinyStr4>().unwrap().is_palindrome());
+}
+
+#[test]
+fn tiny4_trim() {
+    let s: TinyStr4 = " en".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en");
+    assert_eq!(s.trim_end().unwrap(), " en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr4 = "en\t".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en\t");
+    assert_eq!(s.trim_end().unwrap(), "en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr4 = "\na\r".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), "a");
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    let s: TinyStr4 = "\x0b\x0cx".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    assert_eq!("    ".parse::<TinyStr4>().unwrap().trim(), None);
+    assert_eq!(" ".parse::<TinyStr4>().unwrap().trim_start(), None);
+    assert_eq!(" ".parse::<TinyStr4>().unwrap().trim_end(), None);
+    assert_eq!(
+        "\x08a\x0e".parse::<TinyStr4>().unwrap().trim().unwrap(),
+        "\x08a\x0e"
+    );
+}
+
+#[test]
+fn tiny4_split_once_str() {
+    let s: TinyStr4 = "-en".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("", "en")));
+    let s: TinyStr4 = "en-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("en", "")));
+    let s: TinyStr4 = "zh-H".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("zh", "H")));
+    assert_eq!(s.split_once_str('_'), None);
+    assert_eq!(s.split_once_str('é'), None);
+    assert_eq!(s.split_once_str('\0'), None);
+    let s: TinyStr4 = "a-b-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("a", "b-")));
+    assert_eq!(s.split_once_str('-'), s.as_str().split_once('-'));
+}
+
+#[test]
+fn tiny4_eq_ignore_ascii_case() {
+    let s: TinyStr4 = "Zh@[".parse().unwrap();
+    let t: TinyStr4 = "zH@[".parse().unwrap();
+    assert!(s.eq_ignore_ascii_case(&t));
+    assert!(s.eq_ignore_ascii_case_str(t.as_str()));
+    assert!(!s.eq_ignore_ascii_case(&"zh".parse().unwrap()));
+    assert!(!s.eq_ignore_ascii_case_str("Zh@[é"));
+    assert!(!s.eq_ignore_ascii_case_str(""));
+
+    for a in 1..=0x7fu8 {
+        for b in 1..=0x7fu8 {
+            let bytes_a = [b'x', a];
+            let bytes_b = [b'X', b];
+            let sa = std::str::from_utf8(&bytes_a).unwrap();
+            let sb = std::str::from_utf8(&bytes_b).unwrap();
+            let ta: TinyStr4 = sa.parse().unwrap();
+            let tb: TinyStr4 = sb.parse().unwrap();
+            assert_eq!(ta.eq_ignore_ascii_case(&tb), sa.eq_ignore_ascii_case(sb));
+        }
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny4_eq_ignore_ascii_case_matches_str(
+        a in "[\x01-\x7f]{1,4}",
+        b in "[\x01-\x7f]{1,4}",
+        flips in proptest::prelude::any::<u16>(),
+    ) {
+        let folded: String = a
+            .chars()
+            .enumerate()
+            .map(|(i, c)| if flips >> i & 1 == 1 { c.to_ascii_uppercase() } else { c })
+            .collect();
+        let ta: TinyStr4 = a.parse().unwrap();
+        let tb: TinyStr4 = b.parse().unwrap();
+        let tf: TinyStr4 = folded.parse().unwrap();
+        assert_eq!(ta.eq_ignore_ascii_case(&tb), a.eq_ignore_ascii_case(&b));
+        assert!(ta.eq_ignore_ascii_case(&tf));
+        assert!(ta.eq_ignore_ascii_case_str(&folded));
+    }
+}
+
+#[test]
+fn tiny4_fixed_bytes() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(s.to_fixed_bytes::<4>(), s.to_bytes());
+    assert_eq!(s.to_fixed_bytes::<20>()[..4], [0x65, 0x6e, 0, 0]);
+    assert_eq!(&s.to_fixed_bytes::<20>()[..3], &[0x65, 0x6e, 0]);
+    assert_eq!(TinyStr4::from_fixed_bytes(s.to_fixed_bytes::<20>()), Ok(s));
+
+    let text = "abcdefghijklmnop";
+    for len in 1..=4 {
+        let s: TinyStr4 = text[..len].parse().unwrap();
+        let bytes: [u8; 4] = s.to_fixed_bytes();
+        assert_eq!(&bytes[..len], &text.as_bytes()[..len]);
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(TinyStr4::from_fixed_bytes(bytes), Ok(s));
+    }
+
+    assert_eq!(
+        TinyStr4::from_fixed_bytes([b'a', 0, b'b', 0]),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::from_fixed_bytes([b'a', 0xe9, 0, 0]),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::from_fixed_bytes([0; 4]),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+    assert_eq!(
+        TinyStr4::from_fixed_bytes([b'a'; 5]),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny4_fixed_bytes_too_short() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let _: [u8; 3] = s.to_fixed_bytes();
+}
+
+#[test]
+fn tiny4_cmp_ignore_ascii_case() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("cs")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("fr")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("de")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("de").cmp_ignore_ascii_case(&parse("DE")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("De").cmp_ignore_ascii_case(&parse("De")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        parse("a_").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("a").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+
+    let mut v: Vec<TinyStr4> = [
+        "fr", "DE", "cs", "de", "De", "EN", "en", "a_", "A", "[", "`", "zz",
+    ]
+    .iter()
+    .map(|s| parse(s))
+    .collect();
+    let mut expected: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    expected.sort_by(|a, b| {
+        a.to_ascii_lowercase()
+            .cmp(&b.to_ascii_lowercase())
+            .then_with(|| a.cmp(b))
+    });
+    v.sort_by(|a, b| a.cmp_ignore_ascii_case(b));
+    let v: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn tiny4_case_insensitive() {
+    let parse = |s: &str| CaseInsensitive::from(s.parse::<TinyStr4>().unwrap());
+    let upper = parse("GZIP");
+    let lower = parse("gzip");
+    assert_eq!(upper, lower);
+    assert_eq!(upper.cmp(&lower), Ordering::Equal);
+    assert_ne!(upper, parse("gzi"));
+    assert!(parse("DE") > parse("cs"));
+    assert!(parse("DE") < parse("fr"));
+    assert_eq!(upper.to_string(), "GZIP");
+    assert_eq!(format!("{:>5}", lower), " gzip");
+    assert_eq!(*upper, "GZIP");
+    assert_eq!(upper.len(), 4);
+    assert_eq!(upper.into_inner(), "GZIP");
+
+    let hash = |s: &CaseInsensitive<TinyStr4>| {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&upper), hash(&lower));
+
+    let mut map = HashMap::new();
+    map.insert(upper, 1);
+    map.insert(lower, 2);
+    map.insert(parse("Gzip"), 3);
+    map.insert(parse("br"), 4);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&parse("gZiP")], 3);
+
+    let mut tree = BTreeMap::new();
+    tree.insert(upper, 1);
+    tree.insert(lower, 2);
+    tree.insert(parse("BR"), 3);
+    tree.insert(parse("br"), 4);
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree[&parse("gzip")], 2);
+    let keys: Vec<String> = tree.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["BR", "GZIP"]);
+}
+
+#[test]
+fn tiny4_is_ascii_numeric_boundaries() {
+    assert!("9999".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!(!"1/".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!(!":1".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    assert!(!"4a1".parse::<TinyStr4>().unwrap().is_ascii_numeric());
+    for b in 1..=0x7fu8 {
+        for i in 0..4 {
+            let mut bytes = [b'5'; 4];
+            bytes[i] = b;
+            let text = std::str::from_utf8(&bytes[..=i]).unwrap();
+            let s: TinyStr4 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_numeric(),
+                text.chars().all(|c| c.is_ascii_digit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny4_count_classes() {
+    let s: TinyStr4 = "Ab1-".parse().unwrap();
+    assert_eq!(s.count_uppercase(), 1);
+    assert_eq!(s.count_lowercase(), 1);
+    assert_eq!(s.count_digits(), 1);
+    assert_eq!(s.count_alphabetic(), 2);
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=4 {
+            let mut bytes = [b'0'; 4];
+            bytes[len - 1] = b;
+            bytes[0] = b'a';
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr4 = text.parse().unwrap();
+            let count = |f: fn(&char) -> bool| text.chars().filter(f).count();
+            assert_eq!(s.count_uppercase(), count(char::is_ascii_uppercase));
+            assert_eq!(s.count_lowercase(), count(char::is_ascii_lowercase));
+            assert_eq!(s.count_digits(), count(char::is_ascii_digit));
+            assert_eq!(s.count_alphabetic(), count(char::is_ascii_alphabetic));
+        }
+    }
+}
+
+#[test]
+fn tiny4_is_ascii_case_matches_std() {
+    let s: TinyStr4 = "419".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+    let s: TinyStr4 = "en-@".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_uppercase());
+    let s: TinyStr4 = "US-@".parse().unwrap();
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=4 {
+            let mut bytes = [b'1'; 4];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr4 = text.parse().unwrap();
+            assert_eq!(s.is_ascii_lowercase(), text.to_ascii_lowercase() == text);
+            assert_eq!(s.is_ascii_uppercase(), text.to_ascii_uppercase() == text);
+        }
+    }
+}
+
+#[test]
+fn tiny4_from_str_validating() {
+    let text = "en-1";
+    assert_eq!(
+        TinyStr4::from_str_validating(text, |b| b.is_ascii_alphanumeric()),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_validating("en1", |b| b.is_ascii_alphanumeric()),
+        Ok("en1".parse().unwrap())
+    );
+    let letter_or_hyphen = |b: u8| b.is_ascii_alphabetic() || b == b'-';
+    assert_eq!(
+        TinyStr4::from_str_validating("zh-H", letter_or_hyphen),
+        Ok("zh-H".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr4::from_str_validating("a-1", letter_or_hyphen),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(TinyStr4::from_str_validating(text, |_| true), text.parse());
+    assert_eq!(
+        TinyStr4::from_str_validating("419", |b| !b.is_ascii_digit()),
+        Err(Error::FailedValidation { index: 0 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_validating("a\0", |_| false),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4::from_str_validating("", |_| true),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+}
+
+#[test]
+fn tiny4_is_ascii_hexdigit_boundaries() {
+    assert!("09af".parse::<TinyStr4>().unwrap().is_ascii_hexdigit());
+    for bad in ["g", "G", "`", "@", ":", "/"].iter() {
+        for len in 1..=4 {
+            for i in 0..len {
+                let mut text = "F".repeat(len);
+                text.replace_range(i..=i, bad);
+                let s: TinyStr4 = text.parse().unwrap();
+                assert!(!s.is_ascii_hexdigit(), "{:?}", text);
+            }
+        }
+    }
+    for b in 1..=0x7fu8 {
+        for len in 1..=4 {
+            let mut bytes = [b'a'; 4];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr4 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_hexdigit(),
+                text.chars().all(|c| c.is_ascii_hexdigit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny4_swapcase_boundaries() {
+    assert_eq!(
+        "aB1".parse::<TinyStr4>().unwrap().to_ascii_swapcase(),
+        "Ab1"
+    );
+    assert_eq!(
+        "@[`~".parse::<TinyStr4>().unwrap().to_ascii_swapcase(),
+        "@[`~"
+    );
+    assert_eq!(
+        "{AZ}".parse::<TinyStr4>().unwrap().to_ascii_swapcase(),
+        "{az}"
+    );
+    assert_eq!(
+        "@az[".parse::<TinyStr4>().unwrap().to_ascii_swapcase(),
+        "@AZ["
+    );
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s: TinyStr4 = c.to_string().parse().unwrap();
+        let expected = if c.is_ascii_uppercase() {
+            c.to_ascii_lowercase()
+        } else {
+            c.to_ascii_uppercase()
+        };
+        assert_eq!(s.to_ascii_swapcase(), expected.to_string().as_str());
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny4_swapcase_matches_chars(text in "[\x01-\x7f]{1,4}") {
+        let s: TinyStr4 = text.parse().unwrap();
+        let expected: String = text
+            .chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect();
+        assert_eq!(s.to_ascii_swapcase().as_str(), expected);
+    }
+}
+
+#[test]
+fn tiny4_identifiers() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    for (text, word, start) in [
+        ("_foo", true, true),
+        ("foo1", true, true),
+        ("1foo", true, false),
+        ("fo-o", false, false),
+        ("_", true, true),
+        ("9", true, false),
+        ("a b", false, false),
+        ("Z_9z", true, true),
+    ]
+    .iter()
+    {
+        let s = parse(text);
+        assert_eq!(s.is_ascii_word_char(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_continue(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_start(), *start, "{:?}", text);
+    }
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s = parse(&format!("a{}", c));
+        assert_eq!(
+            s.is_ascii_word_char(),
+            c.is_ascii_alphanumeric() || c == '_',
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn tiny4_to_u64() {
+    assert_eq!("419".parse::<TinyStr4>().unwrap().to_u64(), Some(419));
+    assert_eq!("007".parse::<TinyStr4>().unwrap().to_u64(), Some(7));
+    assert_eq!("0".parse::<TinyStr4>().unwrap().to_u64(), Some(0));
+    assert_eq!("4a9".parse::<TinyStr4>().unwrap().to_u64(), None);
+    assert_eq!("-1".parse::<TinyStr4>().unwrap().to_u64(), None);
+    assert_eq!(" 1".parse::<TinyStr4>().unwrap().to_u64(), None);
+    assert_eq!("9999".parse::<TinyStr4>().unwrap().to_u64(), Some(9999));
+}
+
+#[test]
+fn tiny4_titlecase_segments() {
+    fn reference(text: &str, sep: char) -> String {
+        let mut start = true;
+        text.chars()
+            .map(|c| {
+                let out = if start {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                };
+                start = c == sep;
+                out
+            })
+            .collect()
+    }
+
+    let s: TinyStr4 = "a-bc".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_segments('-'), "A-Bc");
+    assert_eq!(s.to_ascii_titlecase_segments('_'), "A-bc");
+    assert_eq!(s.to_ascii_titlecase_segments('é'), s.to_ascii_titlecase());
+    assert_eq!(s.to_ascii_titlecase_segments('\0'), s.to_ascii_titlecase());
+
+    for text in ["-ab", "ab-", "a--b", "-", "1a-b", "A-1B", "X_Y_"].iter() {
+        let s: TinyStr4 = text.parse().unwrap();
+        for sep in ['-', '_'].iter() {
+            assert_eq!(
+                s.to_ascii_titlecase_segments(*sep),
+                reference(text, *sep).as_str(),
+                "{:?} {:?}",
+                text,
+                sep
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny4_upper_lower_first() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    assert_eq!(parse("latn").to_upper_first(), "Latn");
+    assert_eq!(parse("Latn").to_upper_first(), "Latn");
+    assert_eq!(parse("lATN").to_upper_first(), "LATN");
+    assert_eq!(parse("1atn").to_upper_first(), "1atn");
+    assert_eq!(parse("@atn").to_upper_first(), "@atn");
+    assert_eq!(parse("`atn").to_upper_first(), "`atn");
+    assert_eq!(parse("z").to_upper_first(), "Z");
+
+    assert_eq!(parse("LATN").to_lower_first(), "lATN");
+    assert_eq!(parse("lATN").to_lower_first(), "lATN");
+    assert_eq!(parse("[ATN").to_lower_first(), "[ATN");
+    assert_eq!(parse("A").to_lower_first(), "a");
+
+    let s = parse("xYz9");
+    assert_eq!(s.to_upper_first()[1..], s[1..]);
+    assert_eq!(s.to_lower_first(), s);
+}
+
+#[test]
+fn tiny4_canonicalize_subtag() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    for (text, kind, canonical) in [
+        ("en", SubtagKind::Language, "en"),
+        ("EN", SubtagKind::Language, "en"),
+        ("hant", SubtagKind::Script, "Hant"),
+        ("HANT", SubtagKind::Script, "Hant"),
+        ("us", SubtagKind::Region, "US"),
+        ("419", SubtagKind::Region, "419"),
+        ("1901", SubtagKind::Variant, "1901"),
+    ]
+    .iter()
+    {
+        assert_eq!(parse(text).canonicalize_subtag(*kind), *canonical);
+    }
+
+    assert_eq!(parse("en").infer_kind(), Some(SubtagKind::Language));
+    assert_eq!(parse("haw").infer_kind(), Some(SubtagKind::Language));
+    assert_eq!(parse("US").infer_kind(), Some(SubtagKind::Region));
+    assert_eq!(parse("Us").infer_kind(), Some(SubtagKind::Language));
+    assert_eq!(parse("419").infer_kind(), Some(SubtagKind::Region));
+    assert_eq!(parse("Hant").infer_kind(), Some(SubtagKind::Script));
+    assert_eq!(parse("1901").infer_kind(), Some(SubtagKind::Variant));
+    assert_eq!(parse("a").infer_kind(), None);
+    assert_eq!(parse("41").infer_kind(), None);
+    assert_eq!(parse("41a").infer_kind(), None);
+    assert_eq!(parse("a-b").infer_kind(), None);
+    assert_eq!(parse("a1b2").infer_kind(), None);
+
+    for text in ["en", "HANT", "us", "419", "1901"].iter() {
+        let s = parse(text);
+        let kind = s.infer_kind().unwrap();
+        assert_eq!(s.canonicalize_subtag(kind).infer_kind(), Some(kind));
+    }
+}
+
+#[test]
+fn tiny4_strip_prefix_str_private_use() {
+    let s: TinyStr4 = "x-ab".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), Some("ab"));
+    assert_eq!(s.strip_prefix_str("X-"), None);
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("x-abc"), None);
+    let s: TinyStr4 = "en-x".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), None);
+}
+
+#[test]
+fn tiny4_rotate() {
+    let s: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(s.rotate_left(0), s);
+    assert_eq!(s.rotate_right(0), s);
+    assert_eq!(s.rotate_left(s.len()), s);
+    assert_eq!(s.rotate_right(s.len()), s);
+    assert_eq!(s.rotate_left(1), "bcda");
+    assert_eq!(s.rotate_right(1), "dabc");
+    assert_eq!(s.rotate_left(s.len() + 1), s.rotate_left(1));
+
+    for text in &["a", "ab", "abc", "abcd"] {
+        let s: TinyStr4 = text.parse().unwrap();
+        let mut expected = text.as_bytes().to_vec();
+        expected.rotate_left(1);
+        assert_eq!(s.rotate_left(1).as_bytes(), &expected[..]);
+        for n in 0..=2 * s.len() {
+            assert_eq!(s.rotate_left(n).rotate_right(n), s);
+            assert_eq!(s.rotate_right(n).rotate_left(n), s);
+        }
+    }
+}
+
+#[test]
+fn tiny4_subtags_rfc5646() {
+    // Examples from RFC 5646, appendix A. Each starts with a language
+    // subtag, and every subtag that fits has the shape of some kind.
+    for tag in [
+        "de",
+        "zh-Hant",
+        "zh-cmn-Hans-CN",
+        "sr-Latn-RS",
+        "sl-nedis",
+        "de-CH-1901",
+        "hy-Latn-IT-arevela",
+        "es-419",
+        "en-US",
+    ]
+    .iter()
+    {
+        let mut subtags = tag.split('-').filter_map(|s| s.parse::<TinyStr4>().ok());
+        assert!(subtags.next().unwrap().is_language_subtag(), "{}", tag);
+        for s in subtags {
+            let kinds = [
+                s.is_language_subtag(),
+                s.is_script_subtag(),
+                s.is_region_subtag(),
+                s.is_variant_subtag(),
+            ];
+            assert!(kinds.iter().any(|k| *k), "{} in {}", s, tag);
+        }
+    }
+
+    let valid = |s: &str| s.parse::<TinyStr4>().unwrap();
+    assert!(valid("1901").is_variant_subtag());
+    assert!(valid("1994").is_variant_subtag());
+    assert!(!valid("abcd").is_variant_subtag());
+    assert!(!valid("a901").is_variant_subtag());
+    assert!(!valid("190").is_variant_subtag());
+    assert!(!valid("19-1").is_variant_subtag());
+    assert!(!valid("1901").is_script_subtag());
+    assert!(!valid("Lat1").is_script_subtag());
+    assert!(!valid("e4").is_language_subtag());
+    assert!(!valid("e4").is_region_subtag());
+    assert!(!valid("1A").is_region_subtag());
+}
+
+#[test]
+fn tiny4_strip_suffix_str() {
+    let s: TinyStr4 = "en-x".parse().unwrap();
+    assert_eq!(s.strip_suffix_str("-x"), Some("en"));
+    assert_eq!(s.strip_suffix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_suffix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_suffix_str("-X"), None);
+    assert_eq!(s.strip_suffix_str("en"), None);
+    assert_eq!(s.strip_suffix_str("ien-x"), None);
+    let x: TinyStr4 = "x".parse().unwrap();
+    assert_eq!(x.strip_suffix_str("x"), Some(""));
+    assert_eq!(x.strip_suffix_str("xx"), None);
+}
+
+#[test]
+fn tiny4_with_prefix_suffix() {
+    let s: TinyStr4 = "abc".parse().unwrap();
+    let full = s.with_suffix('z').unwrap();
+    assert_eq!(full, "abcz");
+    assert_eq!(s.with_prefix('z').unwrap(), "zabc");
+    assert_eq!(
+        full.with_suffix('z'),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    assert_eq!(
+        full.with_prefix('z'),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+
+    let a: TinyStr4 = "a".parse().unwrap();
+    assert_eq!(a.with_prefix('-').unwrap(), "-a");
+    assert_eq!(a.with_suffix('-').unwrap(), "a-");
+    assert_eq!(a.with_prefix('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(a.with_suffix('é'), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(a.with_prefix('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert_eq!(a.with_suffix('\0'), Err(Error::InvalidNull { index: 1 }));
+}
+
+#[test]
+fn tiny4_to_u64_radix() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    assert_eq!(parse("ff").to_u64_radix(16), Some(255));
+    assert_eq!(parse("FF").to_u64_radix(16), Some(255));
+    assert_eq!(parse("101").to_u64_radix(2), Some(5));
+    assert_eq!(parse("102").to_u64_radix(2), None);
+    assert_eq!(parse("zz").to_u64_radix(36), Some(36 * 36 - 1));
+    assert_eq!(parse("-1").to_u64_radix(10), None);
+    assert_eq!(parse("+1").to_u64_radix(10), None);
+    assert_eq!(parse("ffff").to_u64_radix(16), Some(0xffff));
+    assert_eq!(parse("zzzz").to_u64_radix(36), Some(36u64.pow(4) - 1));
+
+    for text in &["0", "7", "42", "419", "9999", "ab", "x1"] {
+        for radix in &[2, 8, 10, 16, 36] {
+            assert_eq!(
+                parse(text).to_u64_radix(*radix),
+                u64::from_str_radix(text, *radix).ok(),
+                "{} in radix {}",
+                text,
+                radix
+            );
+        }
+        assert_eq!(parse(text).to_u64_radix(10), parse(text).to_u64());
+    }
+}
+
+#[test]
+#[should_panic]
+fn tiny4_to_u64_radix_bad_radix() {
+    let _ = "1".parse::<TinyStr4>().unwrap().to_u64_radix(37);
+}
+
+#[test]
+fn tiny4_common_prefix_len() {
+    let parse = |s: &str| s.parse::<TinyStr4>().unwrap();
+    assert_eq!(parse("enx").common_prefix_len(&parse("eny")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("abc")), 3);
+    assert_eq!(parse("ab").common_prefix_len(&parse("xy")), 0);
+    assert_eq!(parse("ab").common_prefix_len(&parse("abc")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("ab")), 2);
+    assert_eq!(parse("ab").common_prefix_len(&parse("aB")), 1);
+
+    let full = parse("abcd");
+    assert_eq!(full.common_prefix_len(&full), 4);
+    for text in &["a", "ab", "abx", "abcx", "b"] {
+        let s = parse(text);
+        let expected = full
+            .bytes()
+            .zip(s.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        assert_eq!(full.common_prefix_len(&s), expected, "{}", text);
+        assert_eq!(s.common_prefix_len(&full), expected, "{}", text);
+    }
+}
+
+#[test]
+fn tiny4_char_predicates() {
+    let s: TinyStr4 = "en-1".parse().unwrap();
+    assert!(s.all_chars(|c| c.is_ascii_graphic()));
+    assert!(!s.all_chars(|c| c.is_ascii_alphabetic()));
+    assert!(s.any_char(|c| c.is_ascii_digit()));
+    assert!(!s.any_char(|c| c == ' '));
+    assert_eq!(
+        s.all_chars(char::is_alphanumeric),
+        s.chars().all(char::is_alphanumeric)
+    );
+
+    assert_eq!(s.map_chars(|c| c), Ok(s));
+    assert_eq!(
+        s.map_chars(|c| c.to_ascii_uppercase()),
+        Ok(s.to_ascii_uppercase())
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { '_' } else { c }).unwrap(),
+        "en_1"
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { 'é' } else { c }),
+        Err(Error::NonAscii { index: 2 })
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == 'n' { '\0' } else { c }),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny4_from_decimal() {
+    for n in [0, 9, 10, 99, 100, 419, 999, 1000, 9999, 10000, u16::MAX].iter() {
+        let expected = n.to_string();
+        match TinyStr4::from_decimal(*n) {
+            Ok(s) => {
+                assert_eq!(s, expected.as_str());
+                assert_eq!(s.to_u64(), Some(u64::from(*n)));
+            }
+            Err(err) => {
+                assert!(expected.len() > 4, "{}", n);
+                assert_eq!(
+                    err,
+                    Error::InvalidSize {
+                        len: expected.len(),
+                        max: 4
+                    }
+                );
+            }
+        }
+    }
+
+    assert_eq!(TinyStr4::from_decimal(0).unwrap(), "0");
+    assert_eq!(TinyStr4::from_decimal_padded(7, 3).unwrap(), "007");
+    assert_eq!(TinyStr4::from_decimal_padded(0, 2).unwrap(), "00");
+    assert_eq!(TinyStr4::from_decimal_padded(0, 0).unwrap(), "0");
+    assert_eq!(TinyStr4::from_decimal_padded(419, 2).unwrap(), "419");
+    assert_eq!(TinyStr4::from_decimal_padded(1, 4).unwrap().len(), 4);
+    assert_eq!(
+        TinyStr4::from_decimal_padded(1, 5),
+        Err(Error::InvalidSize { len: 5, max: 4 })
+    );
+    for width in 0..=4 {
+        let s = TinyStr4::from_decimal_padded(42, width).unwrap();
+        assert_eq!(s, format!("{:0width$}", 42, width = width).as_str());
+        assert_eq!(s.to_u64(), Some(42));
+    }
+}
+
+#[test]
+fn tiny8_basic() {
+    let s: TinyStr8 = "abcde".parse().unwrap();
+    assert_eq!(s.deref(), "abcde");
+}
+
+#[test]
+fn tiny8_size() {
+    assert_eq!(
+        "".parse::<TinyStr8>(),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert!("1".parse::<TinyStr8>().is_ok());
+    assert!("12".parse::<TinyStr8>().is_ok());
+    assert!("123".parse::<TinyStr8>().is_ok());
+    assert!("1234".parse::<TinyStr8>().is_ok());
+    assert!("12345".parse::<TinyStr8>().is_ok());
+    assert!("123456".parse::<TinyStr8>().is_ok());
+    assert!("1234567".parse::<TinyStr8>().is_ok());
+    assert!("12345678".parse::<TinyStr8>().is_ok());
+    assert_eq!(
+        "123456789".parse::<TinyStr8>(),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+}
+
+#[test]
+fn tiny8_null() {
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr8>(),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        "ab\u{0}d".parse::<TinyStr8>(),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny8_new_unchecked() {
+    let reference: TinyStr8 = "Windows".parse().unwrap();
+    let uval: u64 = reference.into();
+    let s = unsafe { TinyStr8::new_unchecked(uval) };
+    assert_eq!(s, reference);
+    assert_eq!(s, "Windows");
+}
+
+#[test]
+fn tiny8_nonascii() {
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr8>(),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        "abcé".parse::<TinyStr8>(),
+        Err(Error::NonAscii { index: 3 })
+    );
+}
+
+#[test]
+fn tiny8_alpha() {
+    let s: TinyStr8 = "@abcXYZ[".parse().unwrap();
+    assert!(!s.is_ascii_alphanumeric());
+    assert_eq!(s.to_ascii_uppercase().as_str(), "@ABCXYZ[");
+    assert_eq!(s.to_ascii_lowercase().as_str(), "@abcxyz[");
+
+    assert!("abcXYZ"
+        .parse::<TinyStr8>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+}
+
+#[test]
+fn tiny8_titlecase() {
+    assert_eq!(
+        "abcdabcd"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcd"
+    );
+    assert_eq!(
+        "ABCDABCD"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcd"
+    );
+    assert_eq!(
+        "aBCDaBCD"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcd"
+    );
+    assert_eq!(
+        "A123a123"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "A123a123"
+    );
+    assert_eq!(
+        "123a123A"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "123a123a"
+    );
+}
+
+#[test]
+fn tiny8_ord() {
+    let mut v: Vec<TinyStr8> = vec!["nedis".parse().unwrap(), "macos".parse().unwrap()];
+    v.sort();
+
+    assert_eq!(v.get(0).unwrap().as_str(), "macos");
+    assert_eq!(v.get(1).unwrap().as_str(), "nedis");
+}
+
+#[test]
+fn tiny8_eq() {
+    let s1: TinyStr8 = "windows".parse().unwrap();
+    let s2: TinyStr8 = "mac".parse().unwrap();
+    let s3: TinyStr8 = "windows".parse().unwrap();
+
+    assert_eq!(s1, s3);
+    assert_ne!(s1, s2);
+}
+
+#[test]
+fn tiny8_display() {
+    let s: TinyStr8 = "abcdef".parse().unwrap();
+    let mut result = String::new();
+    write!(result, "{}", s).unwrap();
+    assert_eq!(result, "abcdef");
+    assert_eq!(format!("{}", s), "abcdef");
+}
+
+#[test]
+fn tiny8_debug() {
+    let s: TinyStr8 = "abcdef".parse().unwrap();
+    assert_eq!(format!("{:#?}", s), "\"abcdef\"");
+}
+
+#[test]
+fn tiny8_titlecase_words() {
+    assert_eq!(
+        "new_york"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "New_York"
+    );
+    assert_eq!(
+        "en-us"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "En-Us"
+    );
+    assert_eq!(
+        "-en_us"
+            .parse::<TinyStr8>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "-En_Us"
+    );
+}
+
+#[test]
+fn tiny8_alphabetic() {
+    assert!("abcXYZ".parse::<TinyStr8>().unwrap().is_ascii_alphabetic());
+    assert!(!"abc1".parse::<TinyStr8>().unwrap().is_ascii_alphabetic());
+    assert!(!"@az[".parse::<TinyStr8>().unwrap().is_ascii_alphabetic());
+    assert!(!"`az{".parse::<TinyStr8>().unwrap().is_ascii_alphabetic());
+
+    assert!("abc1".parse::<TinyStr8>().unwrap().is_ascii_alphanumeric());
+    assert!("A123a123"
+        .parse::<TinyStr8>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+    assert!("12345678"
+        .parse::<TinyStr8>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+    assert!(!"a/9:".parse::<TinyStr8>().unwrap().is_ascii_alphanumeric());
+}
+
+#[test]
+fn tiny8_len() {
+    assert_eq!(TinyStr8::CAPACITY, 8);
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr8::CAPACITY {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert_eq!(s.len(), len);
+        assert_eq!(s.len(), s.as_str().len());
+        assert_eq!(s.capacity(), 8);
+        assert!(s.len() <= s.capacity());
+        assert!(!s.is_empty());
+    }
+}
+
+#[test]
+fn tiny8_subtags() {
+    let valid = |s: &str| s.parse::<TinyStr8>().unwrap();
+
+    assert!(valid("en").is_language_subtag());
+    assert!(valid("yue").is_language_subtag());
+    assert!(valid("Hant").is_language_subtag());
+    assert!(!valid("419").is_language_subtag());
+    assert!(!valid("e").is_language_subtag());
+    assert!(!valid("e1").is_language_subtag());
+    assert!(!valid("41").is_language_subtag());
+    assert!(!valid("4190").is_language_subtag());
+    assert!(!valid("en-").is_language_subtag());
+
+    assert!(valid("US").is_region_subtag());
+    assert!(valid("419").is_region_subtag());
+    assert!(!valid("U").is_region_subtag());
+    assert!(!valid("USA").is_region_subtag());
+    assert!(!valid("41").is_region_subtag());
+    assert!(!valid("4a9").is_region_subtag());
+
+    assert!(valid("Hant").is_script_subtag());
+    assert!(valid("Latn").is_script_subtag());
+    assert!(valid("hant").is_script_subtag());
+    assert!(valid("HANT").is_script_subtag());
+    assert!(!valid("Han").is_script_subtag());
+    assert!(!valid("Han1").is_script_subtag());
+}
+
+#[test]
+fn tiny8_subtags_long() {
+    let valid = |s: &str| s.parse::<TinyStr8>().unwrap();
+
+    assert!(valid("posix").is_language_subtag());
+    assert!(valid("abcdefgh").is_language_subtag());
+    assert!(!valid("abcdefg1").is_language_subtag());
+    assert!(!valid("Hantx").is_script_subtag());
+}
+
+#[test]
+fn tiny8_as_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr8::CAPACITY {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert_eq!(s.as_bytes().len(), s.len());
+        assert_eq!(s.as_bytes(), s.as_str().as_bytes());
+        assert_eq!(AsRef::<[u8]>::as_ref(&s), &text.as_bytes()[..len]);
+    }
+}
+
+#[test]
+fn tiny8_numeric() {
+    assert!("419".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!("00123456".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!(!"4a9".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!(!"41.9".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+}
+
+#[test]
+fn tiny8_trim_ascii() {
+    let s: TinyStr8 = "   abcd".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "abcd");
+    assert_eq!(s.trim_ascii_end().unwrap(), s);
+    assert_eq!(s.trim_ascii().unwrap(), "abcd");
+
+    let s: TinyStr8 = "abcd   ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), s);
+    assert_eq!(s.trim_ascii_end().unwrap(), "abcd");
+    assert_eq!(s.trim_ascii().unwrap(), "abcd");
+
+    let s: TinyStr8 = " a b    ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "a b    ");
+    assert_eq!(s.trim_ascii_end().unwrap(), " a b");
+    assert_eq!(s.trim_ascii().unwrap(), "a b");
+
+    let s: TinyStr8 = "abcdefgh".parse().unwrap();
+    assert_eq!(s.trim_ascii().unwrap(), s);
+
+    let s: TinyStr8 = "        ".parse().unwrap();
+    assert_eq!(
+        s.trim_ascii_start(),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(
+        s.trim_ascii_end(),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(s.trim_ascii(), Err(Error::InvalidSize { len: 0, max: 8 }));
+}
+
+#[test]
+fn tiny8_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr8::CAPACITY {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        let bytes = s.to_bytes();
+        assert_eq!(&bytes[..len], s.as_bytes());
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(bytes, u64::from(s).to_le_bytes());
+        assert_eq!(TinyStr8::from_bytes_padded(bytes), Ok(s));
+    }
+
+    let mut bytes = [0; 8];
+    assert_eq!(
+        TinyStr8::from_bytes_padded(bytes),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    bytes[0] = b'a';
+    bytes[2] = b'b';
+    assert_eq!(
+        TinyStr8::from_bytes_padded(bytes),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    bytes[1] = 0xe4;
+    assert_eq!(
+        TinyStr8::from_bytes_padded(bytes),
+        Err(Error::NonAscii { index: 1 })
+    );
+}
+
+#[test]
+fn tiny8_case() {
+    let s: TinyStr8 = "ABC12".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+
+    let s: TinyStr8 = "abc12".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+
+    let s: TinyStr8 = "Abc".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_mixed_case());
+
+    let s: TinyStr8 = "12-@".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+}
+
+#[test]
+fn tiny8_replace_char() {
+    let s: TinyStr8 = "en-us".parse().unwrap();
+    assert_eq!(s.replace_char('-', '_').unwrap(), "en_us");
+    assert_eq!(s.replace_char('e', 'E').unwrap(), "En-us");
+    assert_eq!(s.replace_char('$', '_').unwrap(), s);
+    assert_eq!(s.replace_char('\0', '_').unwrap(), s);
+    assert_eq!(s.replace_char('é', '_').unwrap(), s);
+    assert_eq!(s.replace_char('-', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(
+        s.replace_char('-', '\0'),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny8_bytes_iter() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr8::CAPACITY {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert_eq!(s.bytes().len(), len);
+        assert!(s.bytes().eq(text[..len].bytes()));
+        assert!(s.bytes().rev().eq(s.as_str().bytes().rev()));
+    }
+
+    let s: TinyStr8 = "abc".parse().unwrap();
+    let mut bytes = s.bytes();
+    assert_eq!(bytes.next(), Some(b'a'));
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes.next_back(), Some(b'c'));
+    assert_eq!(bytes.clone().next(), Some(b'b'));
+    assert_eq!(bytes.next_back(), Some(b'b'));
+    assert_eq!(bytes.next(), None);
+    assert_eq!(bytes.next_back(), None);
+}
+
+#[test]
+fn tiny8_hexdigit() {
+    assert!("deadBEEF".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    assert!("12ff".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    assert!(!"xyz".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    assert!(!"12g4".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    assert!(!"12G4".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    assert!(!"@`/:".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+}
+
+#[test]
+fn tiny8_swapcase() {
+    let s: TinyStr8 = "Hello".parse().unwrap();
+    assert_eq!(s.to_ascii_swapcase(), "hELLO");
+    assert_eq!(s.to_ascii_swapcase().to_ascii_swapcase(), s);
+}
+
+#[test]
+fn tiny8_graphic() {
+    assert_eq!(
+        TinyStr8::from_str_graphic("en US~!"),
+        Ok("en US~!".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr8::from_str_graphic("a\tb"),
+        Err(Error::NonGraphic { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_graphic("ab\u{7f}"),
+        Err(Error::NonGraphic { index: 2 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_graphic("\u{1}"),
+        Err(Error::NonGraphic { index: 0 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_graphic("a\u{0}b"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert!("a\tb".parse::<TinyStr8>().is_ok());
+}
+
+#[test]
+fn tiny8_chars() {
+    let text = "aB3-@[`{ ~z0Z9_.";
+    for len in 1..=TinyStr8::CAPACITY {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert!(s.chars().eq(text[..len].chars()));
+        assert!(s.chars().rev().eq(text[..len].chars().rev()));
+        assert_eq!(s.chars().len(), len);
+        assert_eq!(s.chars().size_hint(), (len, Some(len)));
+        let mut chars = s.chars();
+        chars.next();
+        assert_eq!(chars.size_hint(), (len - 1, Some(len - 1)));
+        assert!(chars.clone().eq(text[1..len].chars()));
+    }
+}
+
+#[test]
+fn tiny8_titlecase_all_words() {
+    let s: TinyStr8 = "new york".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), "New York");
+    let s: TinyStr8 = "nEWyork".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), s.to_ascii_titlecase());
+    let s: TinyStr8 = " new yo".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), " New Yo");
+    let s: TinyStr8 = "new yo ".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), "New Yo ");
+}
+
+#[test]
+fn tiny8_option_size() {
+    assert_eq!(size_of::<TinyStr8>(), 8);
+    assert_eq!(size_of::<Option<TinyStr8>>(), size_of::<TinyStr8>());
+}
+
+#[test]
+fn tiny8_get() {
+    let s: TinyStr8 = "en-US".parse().unwrap();
+    assert_eq!(s.get(0), Some('e'));
+    assert_eq!(s.get(1), Some('n'));
+    assert_eq!(s.get(s.len() - 1), Some('S'));
+    assert_eq!(s.get(s.len()), None);
+    assert_eq!(s.get(usize::MAX), None);
+    assert_eq!(s.first(), 'e');
+    assert_eq!(s.last(), 'S');
+    for (i, c) in s.as_str().chars().enumerate() {
+        assert_eq!(s.get(i), Some(c));
+        assert!(s.get(i).unwrap().is_ascii());
+    }
+
+    let s: TinyStr8 = "abcdefgh".parse().unwrap();
+    assert_eq!(s.get(8 - 1), Some('h'));
+    assert_eq!(s.get(8), None);
+    assert_eq!(s.last(), 'h');
+
+    let s: TinyStr8 = "x".parse().unwrap();
+    assert_eq!(s.first(), 'x');
+    assert_eq!(s.last(), 'x');
+}
+
+#[test]
+fn tiny8_radix_fmt() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    assert_eq!(format!("{:x}", s), "6e65");
+    assert_eq!(format!("{:X}", s), "6E65");
+    assert_eq!(format!("{:#010x}", s), "0x00006e65");
+    assert_eq!(format!("{:b}", s), "110111001100101");
+    assert_eq!(format!("{:o}", s), "67145");
+
+    let s: TinyStr8 = "Windows".parse().unwrap();
+    let word = u64::from(s);
+    assert_eq!(format!("{:x}", s), format!("{:x}", word));
+    assert_eq!(format!("{:X}", s), format!("{:X}", word));
+    assert_eq!(format!("{:b}", s), format!("{:b}", word));
+    assert_eq!(format!("{:o}", s), format!("{:o}", word));
+}
+
+#[test]
+fn tiny8_index() {
+    let s: TinyStr8 = "abcdefgh".parse().unwrap();
+    assert_eq!(&s[1..3], "bc");
+    assert_eq!(&s[..2], "ab");
+    assert_eq!(&s[6..], "gh");
+    assert_eq!(&s[..], s.as_str());
+    assert_eq!(&s[8..], "");
+    assert_eq!(&s[1..1], "");
+}
+
+#[test]
+#[should_panic]
+fn tiny8_index_out_of_range() {
+    let s: TinyStr8 = "abc".parse().unwrap();
+    let _ = &s[1..4];
+}
+
+#[test]
+fn tiny8_starts_ends_with() {
+    let text = "abcdefgh";
+    let s: TinyStr8 = text.parse().unwrap();
+    for len in 0..=8 {
+        assert!(s.starts_with(&text[..len]));
+        assert!(s.ends_with(&text[len..]));
+    }
+    for pat in &["", "a", "b", "ab", "ba", "gh", "abcdefghijklmnopq", "é"] {
+        assert_eq!(s.starts_with(pat), text.starts_with(pat));
+        assert_eq!(s.ends_with(pat), text.ends_with(pat));
+    }
+    assert!(s.starts_with(String::from("ab")));
+    assert!(s.starts_with_char('a'));
+    assert!(!s.starts_with_char('b'));
+    assert!(s.ends_with_char('h'));
+    assert!(!s.ends_with_char('a'));
+    assert!(!s.ends_with_char('é'));
+
+    let s: TinyStr8 = "x".parse().unwrap();
+    assert!(s.starts_with("x"));
+    assert!(s.ends_with("x"));
+    assert!(!s.starts_with("xy"));
+    assert!(!s.ends_with("yx"));
+    assert!(s.starts_with_char('x'));
+    assert!(s.ends_with_char('x'));
+}
+
+#[test]
+fn tiny8_from_str_lossy() {
+    assert_eq!(TinyStr8::from_str_lossy("en-US", b'?').unwrap(), "en-US");
+    assert_eq!(TinyStr8::from_str_lossy("café", b'?').unwrap(), "caf??");
+    assert_eq!(TinyStr8::from_str_lossy("\u{4000}", b'_').unwrap(), "___");
+    assert_eq!(
+        TinyStr8::from_str_lossy("", b'?'),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy("abcdefghé", b'?'),
+        Err(Error::InvalidSize { len: 10, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy("a\u{0}é", b'?'),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy("é", 0),
+        Err(Error::InvalidNull { index: 0 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy("é", 0xbf),
+        Err(Error::NonAscii { index: 0 })
+    );
+}
+
+#[test]
+fn tiny8_repeat_char() {
+    assert_eq!(TinyStr8::repeat_char('x', 1).unwrap(), "x");
+    assert_eq!(TinyStr8::repeat_char('x', 3).unwrap(), "xxx");
+    assert_eq!(
+        TinyStr8::repeat_char('-', 8).unwrap(),
+        "-".repeat(8).as_str()
+    );
+    assert_eq!(
+        TinyStr8::repeat_char('x', 0),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::repeat_char('x', 9),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::repeat_char('é', 2),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr8::repeat_char('\0', 2),
+        Err(Error::InvalidNull { index: 0 })
+    );
+}
+
+#[test]
+fn tiny8_strip_prefix_suffix() {
+    let s: TinyStr8 = "x-priv".parse().unwrap();
+    assert_eq!(s.strip_prefix("x-").unwrap(), "priv");
+    assert_eq!(s.strip_prefix("").unwrap(), s);
+    assert_eq!(s.strip_prefix("y-"), None);
+    assert_eq!(s.strip_prefix("x-p"), Some("riv".parse().unwrap()));
+    assert_eq!(s.strip_prefix(s.as_str()), None);
+    assert_eq!(s.strip_prefix("x-privat"), None);
+
+    assert_eq!(s.strip_suffix("v").unwrap(), "x-pri");
+    assert_eq!(s.strip_suffix("").unwrap(), s);
+    assert_eq!(s.strip_suffix("x"), None);
+    assert_eq!(s.strip_suffix(s.as_str()), None);
+    assert_eq!(s.strip_suffix("zx-priv"), None);
+}
+
+#[test]
+fn tiny8_from_str_truncating() {
+    let text = "abcdefgh";
+    assert_eq!(TinyStr8::from_str_truncating(text).unwrap(), text);
+    assert_eq!(TinyStr8::from_str_truncating("abcdefghi").unwrap(), text);
+    assert_eq!(
+        TinyStr8::from_str_truncating("abcdefghijklmnop").unwrap(),
+        text
+    );
+    assert_eq!(
+        TinyStr8::from_str_truncating("en-US-poch").unwrap(),
+        "en-US-po"
+    );
+    assert_eq!(TinyStr8::from_str_truncating("abcdefghé").unwrap(), text);
+    assert_eq!(
+        TinyStr8::from_str_truncating("ab"),
+        Ok("ab".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr8::from_str_truncating(""),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_truncating("aécdefgh"),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_truncating("a\u{0}cdefgh"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny8_contains() {
+    let alphabet = ["", "a", "b", "-", "é"];
+    let mut needles: Vec<String> = vec![];
+    for x in &alphabet {
+        for y in &alphabet {
+            for z in &alphabet {
+                needles.push(format!("{}{}{}", x, y, z));
+            }
+        }
+    }
+    for text in &["a", "ab", "ba", "a-b", "ab-ab-ba"] {
+        let s: TinyStr8 = text.parse().unwrap();
+        for needle in &needles {
+            assert_eq!(s.contains(needle), text.contains(needle.as_str()));
+        }
+        for i in 0..text.len() {
+            for j in i..=text.len() {
+                assert!(s.contains(&text[i..j]));
+            }
+        }
+        assert!(!s.contains(&format!("{}a", text)));
+    }
+}
+
+#[test]
+fn tiny8_replace() {
+    let s: TinyStr8 = "en_us_x".parse().unwrap();
+    assert_eq!(s.replace('_', '-').unwrap(), "en-us-x");
+    assert_eq!(s.replace('#', '-').unwrap(), s);
+    assert_eq!(s.replace('\0', '-').unwrap(), s);
+    assert_eq!(s.replace('é', '-'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(s.replace('_', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.replace('_', '\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny8_buf() {
+    let text = "abcdefghijklmnop";
+    let mut buf = TinyStrBuf8::new();
+    assert!(buf.is_empty());
+    assert_eq!(buf.pop(), None);
+    assert_eq!(buf.build(), Err(Error::InvalidSize { len: 0, max: 8 }));
+    for (i, c) in text[..8].chars().enumerate() {
+        buf.push(c).unwrap();
+        assert_eq!(buf.len(), i + 1);
+        assert_eq!(&*buf, &text[..=i]);
+    }
+    assert_eq!(buf.push('x'), Err(Error::InvalidSize { len: 9, max: 8 }));
+    assert_eq!(buf.as_str(), &text[..8]);
+    assert_eq!(buf.build().unwrap(), &text[..8]);
+
+    assert_eq!(buf.pop(), Some('h'));
+    assert_eq!(format!("{}", buf), &text[..7]);
+    assert_eq!(format!("{:?}", buf), format!("{:?}", &text[..7]));
+    buf.clear();
+    assert!(buf.is_empty());
+    assert_eq!(buf.push('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(buf.push('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert!(buf.is_empty());
+
+    let s: TinyStr8 = "en".parse().unwrap();
+    let mut buf = TinyStrBuf8::from(s);
+    assert_eq!(buf.len(), 2);
+    buf.push('-').unwrap();
+    assert_eq!(TinyStr8::from(buf), "en-");
+    assert_eq!(buf.pop(), Some('-'));
+    assert_eq!(TinyStr8::from(buf), s);
+}
+
+#[test]
+fn tiny8_find() {
+    let text = "a-b-c-d-";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        for c in &['a', 'b', '-', 'z', '\0', 'é', '\u{80}'] {
+            assert_eq!(s.find(*c), text[..len].find(*c));
+            assert_eq!(s.rfind(*c), text[..len].rfind(*c));
+        }
+    }
+    let s: TinyStr8 = "x".parse().unwrap();
+    assert_eq!(s.find('x'), Some(0));
+    assert_eq!(s.rfind('x'), Some(0));
+}
+
+#[test]
+fn tiny8_byte_at() {
+    let s: TinyStr8 = "a-Z9@[`{".parse().unwrap();
+    for i in 0..s.len() {
+        assert_eq!(s.byte_at(i), Some(s.as_bytes()[i]));
+        assert_eq!(s.get(i), s.as_str().chars().nth(i));
+    }
+    assert_eq!(s.byte_at(s.len()), None);
+    assert_eq!(s.get(s.len()), None);
+}
+
+#[test]
+fn tiny8_contains_char() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        for c in "\0 -_aehmpqzA~é\u{80}\u{7f}\u{4000}".chars() {
+            assert_eq!(
+                s.contains_char(c),
+                s.as_str().contains(c),
+                "{:?} {:?}",
+                s,
+                c
+            );
+        }
+        for b in 0..=255u8 {
+            assert_eq!(
+                s.contains_byte(b),
+                s.as_bytes().contains(&b),
+                "{:?} {}",
+                s,
+                b
+            );
+        }
+    }
+    let s: TinyStr8 = "a-b".parse().unwrap();
+    assert!(s.contains_char('-'));
+    assert!(!s.contains_char('_'));
+}
+
+#[test]
+fn tiny8_from_iter() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr8 = text[..8].chars().collect();
+    assert_eq!(s, &text[..8]);
+    let s: TinyStr8 = "the quick fox"
+        .split(' ')
+        .filter_map(|w| w.chars().next())
+        .collect();
+    assert_eq!(s, "tqf");
+
+    let c: TinyStr8Collector = text[..8].chars().collect();
+    assert_eq!(c.try_build().unwrap(), &text[..8]);
+    let c: TinyStr8Collector = text[..=8].chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidSize { len: 9, max: 8 }));
+    let c: TinyStr8Collector = "aébc".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 1 }));
+    let c: TinyStr8Collector = "a\0".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidNull { index: 1 }));
+    assert_eq!(
+        TinyStr8Collector::new().try_build(),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+
+    let mut c = TinyStr8Collector::new();
+    c.extend("ab".chars());
+    c.extend("é".chars());
+    c.extend("c".chars());
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 2 }));
+}
+
+#[test]
+#[should_panic]
+fn tiny8_from_iter_overflow() {
+    let _: TinyStr8 = "abcdefghijklmnopq"[..=8].chars().collect();
+}
+
+#[test]
+fn tiny8_from_str_lossy_truncating() {
+    assert_eq!(
+        TinyStr8::from_str_lossy_truncating("0123456789").unwrap(),
+        "01234567"
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy_truncating("caféX\0yz").unwrap(),
+        "caf??X?y"
+    );
+    assert_eq!(
+        TinyStr8::from_str_lossy_truncating("\u{4000}").unwrap(),
+        "???"
+    );
+    assert_eq!(TinyStr8::from_str_lossy_truncating(""), None);
+}
+
+#[test]
+fn tiny8_split() {
+    for text in &[
+        "a", "-", "a-b", "-a", "a-", "--", "a--b", "-ab-", "ab-c", "zh-Hant",
+    ] {
+        let s: TinyStr8 = text.parse().unwrap();
+        let expected: Vec<&str> = text.split('-').filter(|seg| !seg.is_empty()).collect();
+        assert_eq!(s.split('-').collect::<Vec<_>>(), expected, "{:?}", text);
+    }
+    let s: TinyStr8 = "a-b".parse().unwrap();
+    assert_eq!(s.split('_').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('é').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('\0').collect::<Vec<_>>(), vec![s]);
+    let mut it = s.split('-');
+    assert_eq!(it.next().unwrap(), "a");
+    assert_eq!(it.next().unwrap(), "b");
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn tiny8_hash() {
+    let text = "windows";
+    let s: TinyStr8 = text.parse().unwrap();
+    let mut h1 = DefaultHasher::new();
+    text.hash(&mut h1);
+    let mut h2 = DefaultHasher::new();
+    s.hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
+
+    let mut map = HashMap::new();
+    map.insert(s, 1);
+    assert_eq!(map.get(text), Some(&1));
+    assert_eq!(map.get("other"), None);
+}
+
+#[test]
+fn tiny8_split_once() {
+    let s: TinyStr8 = "en-US".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "en");
+    assert_eq!(tail, "US");
+    assert_eq!(s.split_once('_'), None);
+    assert_eq!(s.split_once('é'), None);
+    let s: TinyStr8 = "-en".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr8 = "en-".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr8 = "a-b-c-d".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "a");
+    assert_eq!(tail, "b-c-d");
+}
+
+#[test]
+fn tiny8_split_at() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr8 = text[..8].parse().unwrap();
+    for idx in 1..8 {
+        let (head, tail) = s.split_at(idx);
+        assert_eq!(head, &text[..idx]);
+        assert_eq!(tail.unwrap(), &text[idx..8]);
+    }
+    assert_eq!(s.split_at(8), (s, None));
+    let s: TinyStr8 = "ab".parse().unwrap();
+    assert_eq!(s.split_at(2), (s, None));
+}
+
+#[test]
+#[should_panic]
+fn tiny8_split_at_zero() {
+    let s: TinyStr8 = "ab".parse().unwrap();
+    s.split_at(0);
+}
+
+#[test]
+#[should_panic]
+fn tiny8_split_at_out_of_bounds() {
+    let s: TinyStr8 = "ab".parse().unwrap();
+    s.split_at(3);
+}
+
+#[test]
+fn tiny8_replace_matches_str() {
+    let text = "a-b_c-de--f_gh-i";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        for &(from, to) in &[('-', '_'), ('_', '-'), ('a', 'A'), ('-', '-'), ('z', 'y')] {
+            let expected = text[..len].replace(from, to.encode_utf8(&mut [0; 4]));
+            assert_eq!(s.replace(from, to).unwrap(), expected.as_str());
+            assert_eq!(s.replace(from, to).unwrap().len(), len);
+        }
+    }
+}
+
+#[test]
+fn tiny8_into_string() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        let owned = String::from(s);
+        assert_eq!(owned, &text[..len]);
+        assert_eq!(owned.len(), len);
+        assert_eq!(owned, s.to_string());
+    }
+    let v: Vec<String> = vec!["windows".parse::<TinyStr8>().unwrap()]
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    assert_eq!(v, vec!["windows"]);
+}
+
+#[test]
+fn tiny8_cow() {
+    let s: TinyStr8 = "windows".parse().unwrap();
+    match Cow::from(&s) {
+        Cow::Borrowed(b) => assert_eq!(b, "windows"),
+        Cow::Owned(_) => panic!("expected a borrowed Cow"),
+    }
+    match Cow::from(s) {
+        Cow::Owned(o) => assert_eq!(o, "windows"),
+        Cow::Borrowed(_) => panic!("expected an owned Cow"),
+    }
+    let takes_cow = |c: Cow<str>| c.len();
+    assert_eq!(takes_cow((&s).into()), s.len());
+    assert_eq!(takes_cow(s.into()), s.len());
+}
+
+#[test]
+fn tiny8_reverse() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        let expected: String = text[..len].chars().rev().collect();
+        assert_eq!(s.reverse(), expected.as_str());
+        assert_eq!(s.reverse().len(), len);
+        assert_eq!(s.reverse().reverse(), s);
+    }
+    let s: TinyStr8 = "x".parse().unwrap();
+    assert_eq!(s.reverse(), s);
+    let s: TinyStr8 = "en-US".parse().unwrap();
+    assert_eq!(s.reverse(), "SU-ne");
+}
+
+#[test]
+fn tiny8_try_from_chars() {
+    assert_eq!(
+        TinyStr8::try_from_chars(['e', 'n'].iter().copied()).unwrap(),
+        "en"
+    );
+    assert_eq!(
+        TinyStr8::try_from_chars("abcdefghijklmnop"[..8].chars()).unwrap(),
+        &"abcdefghijklmnop"[..8]
+    );
+    assert_eq!(
+        TinyStr8::try_from_chars("abcdefghijklmnopq"[..=8].chars()),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::try_from_chars("aé".chars()),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::try_from_chars(std::iter::empty()),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+}
+
+#[test]
+fn tiny8_repeat() {
+    let s: TinyStr8 = "ab".parse().unwrap();
+    assert_eq!(s.repeat(1).unwrap(), s);
+    assert_eq!(s.repeat(4).unwrap(), "ab".repeat(4).as_str());
+    assert_eq!(s.repeat(5), Err(Error::InvalidSize { len: 10, max: 8 }));
+    assert_eq!(s.repeat(0), Err(Error::InvalidSize { len: 0, max: 8 }));
+    assert_eq!(
+        s.repeat(usize::MAX),
+        Err(Error::InvalidSize {
+            len: usize::MAX,
+            max: 8
+        })
+    );
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        for n in 1..=8 / len {
+            assert_eq!(s.repeat(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat(8 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny8_repeat16() {
+    let s: TinyStr8 = "ab".parse().unwrap();
+    assert_eq!(s.repeat16(1), Ok(TinyStr16::from(s)));
+    assert_eq!(s.repeat16(8).unwrap(), "ab".repeat(8).as_str());
+    assert_eq!(s.repeat16(9), Err(Error::InvalidSize { len: 18, max: 16 }));
+    assert_eq!(s.repeat16(0), Err(Error::InvalidSize { len: 0, max: 16 }));
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        for n in 1..=16 / len {
+            assert_eq!(s.repeat16(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat16(16 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny8_first_last_match_chars() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert_eq!(Some(s.first()), s.as_str().chars().next());
+        assert_eq!(Some(s.last()), s.as_str().chars().last());
+        assert_eq!(s.first() == s.last(), len == 1);
+    }
+}
+
+#[test]
+fn tiny8_concat() {
+    let text = "abcdefghijklmnop";
+    for len in 1..8 {
+        let a: TinyStr8 = text[..len].parse().unwrap();
+        let b: TinyStr8 = text[len..8].parse().unwrap();
+        let s = a.try_concat(b).unwrap();
+        assert_eq!(s, &text[..8]);
+        assert_eq!(s.len(), 8);
+        assert_eq!(&*s, &text[..8]);
+        assert_eq!(a + b, s);
+        let parsed: TinyStr8 = text[..8].parse().unwrap();
+        assert_eq!(s.cmp(&parsed), Ordering::Equal);
+        assert_eq!(s.cmp(&a), Ordering::Greater);
+    }
+    let en: TinyStr8 = "en".parse().unwrap();
+    let dash: TinyStr8 = "-".parse().unwrap();
+    assert_eq!((en + dash).try_concat(dash).unwrap(), "en--");
+    let full: TinyStr8 = text[..8].parse().unwrap();
+    assert_eq!(
+        full.try_concat(dash),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny8_add_overflow() {
+    let full: TinyStr8 = "abcdefghijklmnop"[..8].parse().unwrap();
+    let _ = full + full;
+}
+
+#[test]
+fn tiny8_concat_widening() {
+    let text = "abcdefghijklmnop";
+    for i in 1..=8 {
+        for j in 1..=8 {
+            let a: TinyStr8 = text[..i].parse().unwrap();
+            let b: TinyStr8 = text[i..i + j].parse().unwrap();
+            let s = a.concat(b);
+            assert_eq!(s, &text[..i + j]);
+            assert_eq!(s.len(), i + j);
+            assert_eq!(s.cmp(&text[..i + j].parse().unwrap()), Ordering::Equal);
+        }
+    }
+}
+
+#[test]
+fn tiny8_display_format() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    assert_eq!(format!("{:>8}", s), "      en");
+    assert_eq!(format!("{:<6}|", s), "en    |");
+    assert_eq!(format!("{:*^6}", s), "**en**");
+    assert_eq!(format!("{:.1}", s), "e");
+    assert_eq!(format!("{:>4.1}", s), "   e");
+    let buf = TinyStrBuf8::from(s);
+    assert_eq!(format!("{:-<5}", buf), "en---");
+}
+
+#[test]
+fn tiny8_strip_char() {
+    let s: TinyStr8 = "-en-US-".parse().unwrap();
+    assert_eq!(s.strip_prefix_char('-'), Some("en-US-"));
+    assert_eq!(s.strip_suffix_char('-'), Some("-en-US"));
+    assert_eq!(s.strip_prefix_char('e'), None);
+    assert_eq!(s.strip_suffix_char('n'), None);
+    assert_eq!(s.strip_prefix_char('é'), None);
+    assert_eq!(s.strip_prefix_str("-"), Some("en-US-"));
+    assert_eq!(s.strip_prefix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("en"), None);
+    let x: TinyStr8 = "x".parse().unwrap();
+    assert_eq!(x.strip_prefix_char('x'), Some(""));
+    assert_eq!(x.strip_suffix_char('x'), Some(""));
+    assert_eq!(x.strip_prefix_char('y'), None);
+}
+
+#[test]
+fn tiny8_as_raw_hex() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    assert_eq!(s.as_raw_hex(), "0x0000000000006e65");
+    assert_eq!(s.as_raw_hex().len(), 2 + 2 * 8);
+    assert_eq!(format!("{:#?}", s), "\"en\"");
+}
+
+#[test]
+fn tiny8_try_push() {
+    let text = "abcdefghijklmnop";
+    let mut s: TinyStr8 = "a".parse().unwrap();
+    for len in 2..=8 {
+        s = s.try_push(text.as_bytes()[len - 1] as char).unwrap();
+        assert_eq!(s, &text[..len]);
+    }
+    assert_eq!(s.try_push('x'), Err(Error::InvalidSize { len: 9, max: 8 }));
+    let s: TinyStr8 = "ab".parse().unwrap();
+    assert_eq!(s.try_push('é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\u{4000}'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny8_try_push_str() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr8 = "a".parse().unwrap();
+    assert_eq!(s.try_push_str(&text[1..8]).unwrap(), &text[..8]);
+    assert_eq!(s.try_push_str("").unwrap(), s);
+    assert_eq!(
+        s.try_push_str(&text[1..=8]),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        s.try_push_str(text),
+        Err(Error::InvalidSize { len: 18, max: 8 })
+    );
+    let full: TinyStr8 = text[..8].parse().unwrap();
+    assert_eq!(
+        full.try_push_str("x"),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(s.try_push_str("é"), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(s.try_push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny8_concat8() {
+    let p = |s: &str| s.parse::<TinyStr8>().unwrap();
+    let s = tinystr::concat8(p("windows"), p("-x64")).unwrap();
+    assert_eq!(s, "windows-x64");
+    assert_eq!(&*s, "windows-x64");
+    assert_eq!(tinystr::concat8(p("a"), p("b")).unwrap(), "ab");
+    assert_eq!(
+        tinystr::concat8(p("abcdefgh"), p("ijklmnop")).unwrap(),
+        "abcdefghijklmnop"
+    );
+}
+
+#[test]
+fn tiny8_byte_layout() {
+    // These hold on every target; CI also runs them on big-endian s390x.
+    let fr: TinyStr8 = "fr".parse().unwrap();
+    let zh: TinyStr8 = "zh".parse().unwrap();
+    assert!(fr < zh);
+    assert!("a".parse::<TinyStr8>().unwrap() < "aa".parse::<TinyStr8>().unwrap());
+    assert!("aa".parse::<TinyStr8>().unwrap() < "b".parse::<TinyStr8>().unwrap());
+    assert_eq!(fr.to_bytes()[..3], *b"fr\0");
+    assert_eq!(u64::from(fr), 0x7266);
+    assert_eq!(unsafe { TinyStr8::new_unchecked(0x7266) }, "fr");
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        assert_eq!(s.deref().len(), len);
+        assert_eq!(s.to_string(), &text[..len]);
+        assert_eq!(
+            s.to_ascii_uppercase(),
+            text[..len].to_ascii_uppercase().as_str()
+        );
+        assert_eq!(s.last(), text.as_bytes()[len - 1] as char);
+    }
+}
+
+#[test]
+fn tiny8_buf_write() {
+    let (prefix, idx, long, e_acute) = ("x", 7, "abcdefghijklmnop", 'é');
+    let mut buf = TinyStrBuf8::new();
+    assert_eq!(buf.remaining_capacity(), 8);
+    write!(buf, "{}{:02}", prefix, idx).unwrap();
+    assert_eq!(buf.as_str(), "x07");
+    assert_eq!(buf.remaining_capacity(), 5);
+    buf.push_str("abcde").unwrap();
+    assert_eq!(buf.remaining_capacity(), 0);
+    assert_eq!(buf.build().unwrap(), "x07abcde");
+    assert!(write!(buf, "y").is_err());
+
+    let mut buf = TinyStrBuf8::new();
+    assert!(write!(buf, "ab-{}", long).is_err());
+    assert_eq!(buf.as_str(), "ab-");
+    let mut buf = TinyStrBuf8::new();
+    assert!(write!(buf, "a{}", e_acute).is_err());
+    assert_eq!(buf.as_str(), "a");
+    assert_eq!(buf.push_str("bé"), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(buf.push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+    assert_eq!(
+        buf.push_str("abcdefghijklmnop"),
+        Err(Error::InvalidSize { len: 17, max: 8 })
+    );
+    assert_eq!(buf.as_str(), "a");
+}
+
+#[test]
+fn tiny8_builder() {
+    struct Accented;
+    impl fmt::Display for Accented {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("é")
+        }
+    }
+
+    let prefix = "tok";
+    let mut builder = TinyStr8Builder::new();
+    write!(builder, "{}-{:04}", prefix, 42).unwrap();
+    assert_eq!(builder.remaining_capacity(), 0);
+    assert_eq!(
+        builder.finish(),
+        Ok("tok-0042".parse::<TinyStr8>().unwrap())
+    );
+
+    let mut builder = TinyStr8Builder::new();
+    assert!(write!(builder, "{}-{:04}", prefix, 42000).is_err());
+    assert_eq!(builder.as_str(), "tok-");
+
+    let mut builder = TinyStr8Builder::new();
+    builder.push('a').unwrap();
+    assert!(write!(builder, "{}", Accented).is_err());
+    assert_eq!(builder.finish(), Ok("a".parse::<TinyStr8>().unwrap()));
+
+    assert_eq!(
+        TinyStr8Builder::new().finish(),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+}
+
+#[test]
+fn tiny8_count_char() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr8 = text[..8].parse().unwrap();
+    for c in text[..8].chars() {
+        assert_eq!(s.count_char(c), 1);
+    }
+    assert_eq!(s.count_char('z'), 0);
+    assert_eq!(s.count_char('é'), 0);
+    assert_eq!(s.count_char('\0'), 0);
+    let s: TinyStr8 = "aa-a-aa-".parse().unwrap();
+    assert_eq!(s.count_char('a'), s.as_str().matches('a').count());
+    assert_eq!(s.count_char('-'), s.as_str().matches('-').count());
+    let s: TinyStr8 = "x".repeat(8).parse().unwrap();
+    assert_eq!(s.count_char('x'), 8);
+}
+
+#[test]
+fn tiny8_parse_rejects_every_position() {
+    for len in 1..=8 {
+        for pos in 0..len {
+            let mut bytes = vec![b'a'; len];
+            bytes[pos] = 0;
+            let text = String::from_utf8(bytes).unwrap();
+            assert_eq!(
+                text.parse::<TinyStr8>(),
+                Err(Error::InvalidNull { index: pos })
+            );
+            if pos + 1 < len {
+                let text = format!("{}é{}", "a".repeat(pos), "a".repeat(len - pos - 2));
+                assert_eq!(
+                    text.parse::<TinyStr8>(),
+                    Err(Error::NonAscii { index: pos })
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tiny8_from_display() {
+    struct Repeat(usize);
+
+    impl fmt::Display for Repeat {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for _ in 0..self.0 {
+                f.write_str("ab")?;
+            }
+            Ok(())
+        }
+    }
+
+    assert_eq!(TinyStr8::from_display(&42).unwrap(), "42");
+    assert_eq!(
+        TinyStr8::from_display(&Repeat(4)).unwrap(),
+        "ab".repeat(4).as_str()
+    );
+    assert_eq!(
+        TinyStr8::from_display(&Repeat(5)),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::from_display(&'é'),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr8::from_display(&""),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+
+    let idx = 7;
+    assert_eq!(format_tiny!(TinyStr8, "x{:03}", idx).unwrap(), "x007");
+    assert_eq!(
+        format_tiny!(TinyStr8, "{:>8}", idx).unwrap(),
+        format!("{:>8}", idx).as_str()
+    );
+    assert_eq!(
+        format_tiny!(TinyStr8, "{:>9}", idx),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        format_tiny!(TinyStr8, "a{}", "\0"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny8_is_palindrome() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    assert_eq!(s.reverse(), "ne");
+    assert!(!s.is_palindrome());
+    assert!("x".parse::<TinyStr8>().unwrap().is_palindrome());
+    assert!("aa".parse::<TinyStr8>().unwrap().is_palindrome());
+    assert!("aba".parse::<TinyStr8>().unwrap().is_palindrome());
+    assert!("racecar".parse::<TinyStr8>().unwrap().is_palindrome());
+    assert!(!"racecars".parse::<TinyStr8>().unwrap().is_palindrome());
+}
+
+#[test]
+fn tiny8_trim() {
+    let s: TinyStr8 = " en".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en");
+    assert_eq!(s.trim_end().unwrap(), " en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr8 = "en\t".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en\t");
+    assert_eq!(s.trim_end().unwrap(), "en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr8 = "\na b\r".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), "a b");
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    let s: TinyStr8 = "\x0b\x0cx".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    assert_eq!(" \t\r\n  ".parse::<TinyStr8>().unwrap().trim(), None);
+    assert_eq!(" ".parse::<TinyStr8>().unwrap().trim_start(), None);
+    assert_eq!(" ".parse::<TinyStr8>().unwrap().trim_end(), None);
+    assert_eq!(
+        "\x08a\x0e".parse::<TinyStr8>().unwrap().trim().unwrap(),
+        "\x08a\x0e"
+    );
+}
+
+#[test]
+fn tiny8_split_once_str() {
+    let s: TinyStr8 = "-en".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("", "en")));
+    let s: TinyStr8 = "en-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("en", "")));
+    let s: TinyStr8 = "zh-Hant".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("zh", "Hant")));
+    assert_eq!(s.split_once_str('_'), None);
+    assert_eq!(s.split_once_str('é'), None);
+    assert_eq!(s.split_once_str('\0'), None);
+    let s: TinyStr8 = "a-b-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("a", "b-")));
+    assert_eq!(s.split_once_str('-'), s.as_str().split_once('-'));
+}
+
+#[test]
+fn tiny8_eq_ignore_ascii_case() {
+    let s: TinyStr8 = "Zh@[`~".parse().unwrap();
+    let t: TinyStr8 = "zH@[`~".parse().unwrap();
+    assert!(s.eq_ignore_ascii_case(&t));
+    assert!(s.eq_ignore_ascii_case_str(t.as_str()));
+    assert!(!s.eq_ignore_ascii_case(&"zh".parse().unwrap()));
+    assert!(!s.eq_ignore_ascii_case_str("Zh@[`~é"));
+    assert!(!s.eq_ignore_ascii_case_str(""));
+
+    for a in 1..=0x7fu8 {
+        for b in 1..=0x7fu8 {
+            let bytes_a = [b'x', a];
+            let bytes_b = [b'X', b];
+            let sa = std::str::from_utf8(&bytes_a).unwrap();
+            let sb = std::str::from_utf8(&bytes_b).unwrap();
+            let ta: TinyStr8 = sa.parse().unwrap();
+            let tb: TinyStr8 = sb.parse().unwrap();
+            assert_eq!(ta.eq_ignore_ascii_case(&tb), sa.eq_ignore_ascii_case(sb));
+        }
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny8_eq_ignore_ascii_case_matches_str(
+        a in "[\x01-\x7f]{1,8}",
+        b in "[\x01-\x7f]{1,8}",
+        flips in proptest::prelude::any::<u16>(),
+    ) {
+        let folded: String = a
+            .chars()
+            .enumerate()
+            .map(|(i, c)| if flips >> i & 1 == 1 { c.to_ascii_uppercase() } else { c })
+            .collect();
+        let ta: TinyStr8 = a.parse().unwrap();
+        let tb: TinyStr8 = b.parse().unwrap();
+        let tf: TinyStr8 = folded.parse().unwrap();
+        assert_eq!(ta.eq_ignore_ascii_case(&tb), a.eq_ignore_ascii_case(&b));
+        assert!(ta.eq_ignore_ascii_case(&tf));
+        assert!(ta.eq_ignore_ascii_case_str(&folded));
+    }
+}
+
+#[test]
+fn tiny8_fixed_bytes() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    assert_eq!(s.to_fixed_bytes::<8>(), s.to_bytes());
+    assert_eq!(s.to_fixed_bytes::<20>()[..4], [0x65, 0x6e, 0, 0]);
+    assert_eq!(&s.to_fixed_bytes::<20>()[..3], &[0x65, 0x6e, 0]);
+    assert_eq!(TinyStr8::from_fixed_bytes(s.to_fixed_bytes::<20>()), Ok(s));
+
+    let text = "abcdefghijklmnop";
+    for len in 1..=8 {
+        let s: TinyStr8 = text[..len].parse().unwrap();
+        let bytes: [u8; 8] = s.to_fixed_bytes();
+        assert_eq!(&bytes[..len], &text.as_bytes()[..len]);
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(TinyStr8::from_fixed_bytes(bytes), Ok(s));
+    }
+
+    assert_eq!(
+        TinyStr8::from_fixed_bytes([b'a', 0, b'b', 0]),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_fixed_bytes([b'a', 0xe9, 0, 0]),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_fixed_bytes([0; 4]),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+    assert_eq!(
+        TinyStr8::from_fixed_bytes([b'a'; 9]),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny8_fixed_bytes_too_short() {
+    let s: TinyStr8 = "en".parse().unwrap();
+    let _: [u8; 7] = s.to_fixed_bytes();
+}
+
+#[test]
+fn tiny8_cmp_ignore_ascii_case() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("cs")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("fr")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("de")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("de").cmp_ignore_ascii_case(&parse("DE")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("De").cmp_ignore_ascii_case(&parse("De")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        parse("a_").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("a").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+
+    let mut v: Vec<TinyStr8> = [
+        "fr", "DE", "cs", "de", "De", "EN", "en", "a_", "A", "[", "`", "zz",
+    ]
+    .iter()
+    .map(|s| parse(s))
+    .collect();
+    let mut expected: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    expected.sort_by(|a, b| {
+        a.to_ascii_lowercase()
+            .cmp(&b.to_ascii_lowercase())
+            .then_with(|| a.cmp(b))
+    });
+    v.sort_by(|a, b| a.cmp_ignore_ascii_case(b));
+    let v: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn tiny8_halves() {
+    let lo: TinyStr4 = "zhHa".parse().unwrap();
+    let hi: TinyStr4 = "nt".parse().unwrap();
+    let s = TinyStr8::from_halves(lo, hi).unwrap();
+    assert_eq!(s, "zhHant");
+    assert_eq!(s.into_halves(), (lo, Some(hi)));
+
+    let full: TinyStr8 = "abcdefgh".parse().unwrap();
+    let (lo, hi) = full.into_halves();
+    assert_eq!(lo, "abcd");
+    assert_eq!(hi.unwrap(), "efgh");
+    assert_eq!(TinyStr8::from_halves(lo, hi.unwrap()), Ok(full));
+
+    let s: TinyStr8 = "zhHa".parse().unwrap();
+    assert_eq!(s.into_halves(), (s.as_str().parse().unwrap(), None));
+
+    let short: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(
+        TinyStr8::from_halves(short, short),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny8_case_insensitive() {
+    let parse = |s: &str| CaseInsensitive::from(s.parse::<TinyStr8>().unwrap());
+    let upper = parse("GZIP");
+    let lower = parse("gzip");
+    assert_eq!(upper, lower);
+    assert_eq!(upper.cmp(&lower), Ordering::Equal);
+    assert_ne!(upper, parse("gzi"));
+    assert!(parse("DE") > parse("cs"));
+    assert!(parse("DE") < parse("fr"));
+    assert_eq!(upper.to_string(), "GZIP");
+    assert_eq!(format!("{:>5}", lower), " gzip");
+    assert_eq!(*upper, "GZIP");
+    assert_eq!(upper.len(), 4);
+    assert_eq!(upper.into_inner(), "GZIP");
+
+    let hash = |s: &CaseInsensitive<TinyStr8>| {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&upper), hash(&lower));
+
+    let mut map = HashMap::new();
+    map.insert(upper, 1);
+    map.insert(lower, 2);
+    map.insert(parse("Gzip"), 3);
+    map.insert(parse("br"), 4);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&parse("gZiP")], 3);
+
+    let mut tree = BTreeMap::new();
+    tree.insert(upper, 1);
+    tree.insert(lower, 2);
+    tree.insert(parse("BR"), 3);
+    tree.insert(parse("br"), 4);
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree[&parse("gzip")], 2);
+    let keys: Vec<String> = tree.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["BR", "GZIP"]);
+}
+
+#[test]
+fn tiny8_is_ascii_numeric_boundaries() {
+    assert!("99999999".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!(!"1/".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!(!":1".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    assert!(!"419abc1".parse::<TinyStr8>().unwrap().is_ascii_numeric());
+    for b in 1..=0x7fu8 {
+        for i in 0..8 {
+            let mut bytes = [b'5'; 8];
+            bytes[i] = b;
+            let text = std::str::from_utf8(&bytes[..=i]).unwrap();
+            let s: TinyStr8 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_numeric(),
+                text.chars().all(|c| c.is_ascii_digit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny8_count_classes() {
+    let s: TinyStr8 = "zhHant-1".parse().unwrap();
+    assert_eq!(s.count_uppercase(), 1);
+    assert_eq!(s.count_lowercase(), 5);
+    assert_eq!(s.count_digits(), 1);
+    assert_eq!(s.count_alphabetic(), 6);
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=8 {
+            let mut bytes = [b'0'; 8];
+            bytes[len - 1] = b;
+            bytes[0] = b'a';
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr8 = text.parse().unwrap();
+            let count = |f: fn(&char) -> bool| text.chars().filter(f).count();
+            assert_eq!(s.count_uppercase(), count(char::is_ascii_uppercase));
+            assert_eq!(s.count_lowercase(), count(char::is_ascii_lowercase));
+            assert_eq!(s.count_digits(), count(char::is_ascii_digit));
+            assert_eq!(s.count_alphabetic(), count(char::is_ascii_alphabetic));
+        }
+    }
+}
+
+#[test]
+fn tiny8_is_ascii_case_matches_std() {
+    let s: TinyStr8 = "419".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+    let s: TinyStr8 = "zh-hant_".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_uppercase());
+    let s: TinyStr8 = "ZH-HANT_".parse().unwrap();
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=8 {
+            let mut bytes = [b'1'; 8];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr8 = text.parse().unwrap();
+            assert_eq!(s.is_ascii_lowercase(), text.to_ascii_lowercase() == text);
+            assert_eq!(s.is_ascii_uppercase(), text.to_ascii_uppercase() == text);
+        }
+    }
+}
+
+#[test]
+fn tiny8_from_str_validating() {
+    let text = "en-1";
+    assert_eq!(
+        TinyStr8::from_str_validating(text, |b| b.is_ascii_alphanumeric()),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_validating("en1", |b| b.is_ascii_alphanumeric()),
+        Ok("en1".parse().unwrap())
+    );
+    let letter_or_hyphen = |b: u8| b.is_ascii_alphabetic() || b == b'-';
+    assert_eq!(
+        TinyStr8::from_str_validating("zh-Hant", letter_or_hyphen),
+        Ok("zh-Hant".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr8::from_str_validating("a-1", letter_or_hyphen),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(TinyStr8::from_str_validating(text, |_| true), text.parse());
+    assert_eq!(
+        TinyStr8::from_str_validating("419", |b| !b.is_ascii_digit()),
+        Err(Error::FailedValidation { index: 0 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_validating("a\0", |_| false),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr8::from_str_validating("", |_| true),
+        Err(Error::InvalidSize { len: 0, max: 8 })
+    );
+}
+
+#[test]
+fn tiny8_is_ascii_hexdigit_boundaries() {
+    assert!("09afAF19".parse::<TinyStr8>().unwrap().is_ascii_hexdigit());
+    for bad in ["g", "G", "`", "@", ":", "/"].iter() {
+        for len in 1..=8 {
+            for i in 0..len {
+                let mut text = "F".repeat(len);
+                text.replace_range(i..=i, bad);
+                let s: TinyStr8 = text.parse().unwrap();
+                assert!(!s.is_ascii_hexdigit(), "{:?}", text);
+            }
+        }
+    }
+    for b in 1..=0x7fu8 {
+        for len in 1..=8 {
+            let mut bytes = [b'a'; 8];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr8 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_hexdigit(),
+                text.chars().all(|c| c.is_ascii_hexdigit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny8_swapcase_boundaries() {
+    assert_eq!(
+        "aB1".parse::<TinyStr8>().unwrap().to_ascii_swapcase(),
+        "Ab1"
+    );
+    assert_eq!(
+        "@[`~".parse::<TinyStr8>().unwrap().to_ascii_swapcase(),
+        "@[`~"
+    );
+    assert_eq!(
+        "{AZ}".parse::<TinyStr8>().unwrap().to_ascii_swapcase(),
+        "{az}"
+    );
+    assert_eq!(
+        "@az[".parse::<TinyStr8>().unwrap().to_ascii_swapcase(),
+        "@AZ["
+    );
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s: TinyStr8 = c.to_string().parse().unwrap();
+        let expected = if c.is_ascii_uppercase() {
+            c.to_ascii_lowercase()
+        } else {
+            c.to_ascii_uppercase()
+        };
+        assert_eq!(s.to_ascii_swapcase(), expected.to_string().as_str());
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny8_swapcase_matches_chars(text in "[\x01-\x7f]{1,8}") {
+        let s: TinyStr8 = text.parse().unwrap();
+        let expected: String = text
+            .chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect();
+        assert_eq!(s.to_ascii_swapcase().as_str(), expected);
+    }
+}
+
+#[test]
+fn tiny8_identifiers() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    for (text, word, start) in [
+        ("_foo", true, true),
+        ("foo1", true, true),
+        ("1foo", true, false),
+        ("fo-o", false, false),
+        ("_", true, true),
+        ("9", true, false),
+        ("a b", false, false),
+        ("Z_9zA_9z", true, true),
+    ]
+    .iter()
+    {
+        let s = parse(text);
+        assert_eq!(s.is_ascii_word_char(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_continue(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_start(), *start, "{:?}", text);
+    }
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s = parse(&format!("a{}", c));
+        assert_eq!(
+            s.is_ascii_word_char(),
+            c.is_ascii_alphanumeric() || c == '_',
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn tiny8_to_u64() {
+    assert_eq!("419".parse::<TinyStr8>().unwrap().to_u64(), Some(419));
+    assert_eq!("007".parse::<TinyStr8>().unwrap().to_u64(), Some(7));
+    assert_eq!("0".parse::<TinyStr8>().unwrap().to_u64(), Some(0));
+    assert_eq!("4a9".parse::<TinyStr8>().unwrap().to_u64(), None);
+    assert_eq!("-1".parse::<TinyStr8>().unwrap().to_u64(), None);
+    assert_eq!(" 1".parse::<TinyStr8>().unwrap().to_u64(), None);
+    assert_eq!(
+        "99999999".parse::<TinyStr8>().unwrap().to_u64(),
+        Some(99_999_999)
+    );
+}
+
+#[test]
+fn tiny8_titlecase_segments() {
+    fn reference(text: &str, sep: char) -> String {
+        let mut start = true;
+        text.chars()
+            .map(|c| {
+                let out = if start {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                };
+                start = c == sep;
+                out
+            })
+            .collect()
+    }
+
+    let s: TinyStr8 = "zh-hant".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_segments('-'), "Zh-Hant");
+    assert_eq!(s.to_ascii_titlecase_segments('_'), "Zh-hant");
+    assert_eq!(s.to_ascii_titlecase_segments('é'), s.to_ascii_titlecase());
+    assert_eq!(s.to_ascii_titlecase_segments('\0'), s.to_ascii_titlecase());
+
+    for text in ["-ab", "ab-", "a--b", "-", "1a-b", "A-1B", "x_y_z_w"].iter() {
+        let s: TinyStr8 = text.parse().unwrap();
+        for sep in ['-', '_'].iter() {
+            assert_eq!(
+                s.to_ascii_titlecase_segments(*sep),
+                reference(text, *sep).as_str(),
+                "{:?} {:?}",
+                text,
+                sep
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny8_upper_lower_first() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    assert_eq!(parse("latn").to_upper_first(), "Latn");
+    assert_eq!(parse("Latn").to_upper_first(), "Latn");
+    assert_eq!(parse("lATN").to_upper_first(), "LATN");
+    assert_eq!(parse("1atn").to_upper_first(), "1atn");
+    assert_eq!(parse("@atn").to_upper_first(), "@atn");
+    assert_eq!(parse("`atn").to_upper_first(), "`atn");
+    assert_eq!(parse("z").to_upper_first(), "Z");
+
+    assert_eq!(parse("LATN").to_lower_first(), "lATN");
+    assert_eq!(parse("lATN").to_lower_first(), "lATN");
+    assert_eq!(parse("[ATN").to_lower_first(), "[ATN");
+    assert_eq!(parse("A").to_lower_first(), "a");
+
+    let s = parse("xYz9-aB_");
+    assert_eq!(s.to_upper_first()[1..], s[1..]);
+    assert_eq!(s.to_lower_first(), s);
+}
+
+#[test]
+fn tiny8_canonicalize_subtag() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    for (text, kind, canonical) in [
+        ("en", SubtagKind::Language, "en"),
+        ("EN", SubtagKind::Language, "en"),
+        ("hant", SubtagKind::Script, "Hant"),
+        ("HANT", SubtagKind::Script, "Hant"),
+        ("us", SubtagKind::Region, "US"),
+        ("419", SubtagKind::Region, "419"),
+        ("POSIX", SubtagKind::Variant, "posix"),
+        ("1901", SubtagKind::Variant, "1901"),
+    ]
+    .iter()
+    {
+        assert_eq!(parse(text).canonicalize_subtag(*kind), *canonical);
+    }
+
+    assert_eq!(parse("en").infer_kind(), Some(SubtagKind::Language));
+    assert_eq!(parse("haw").infer_kind(), Some(SubtagKind::Language));
+    assert_eq!(parse("US").infer_kind(), Some(SubtagKind::Region));
+    assert_eq!(parse("419").infer_kind(), Some(SubtagKind::Region));
+    assert_eq!(parse("Hant").infer_kind(), Some(SubtagKind::Script));
+    assert_eq!(parse("posix").infer_kind(), Some(SubtagKind::Variant));
+    assert_eq!(parse("1994").infer_kind(), Some(SubtagKind::Variant));
+    assert_eq!(parse("rozaj12").infer_kind(), Some(SubtagKind::Variant));
+    assert_eq!(parse("a").infer_kind(), None);
+    assert_eq!(parse("a1b2").infer_kind(), None);
+    assert_eq!(parse("posix-1").infer_kind(), None);
+
+    for text in ["en", "HANT", "us", "419", "POSIX"].iter() {
+        let s = parse(text);
+        let kind = s.infer_kind().unwrap();
+        assert_eq!(s.canonicalize_subtag(kind).infer_kind(), Some(kind));
+    }
+}
+
+#[test]
+fn tiny8_strip_prefix_str_private_use() {
+    let s: TinyStr8 = "x-abcde".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), Some("abcde"));
+    assert_eq!(s.strip_prefix_str("X-"), None);
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("x-abcdef"), None);
+    let s: TinyStr8 = "en-x".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), None);
+}
+
+#[test]
+fn tiny8_rotate() {
+    let s: TinyStr8 = "abcdefgh".parse().unwrap();
+    assert_eq!(s.rotate_left(0), s);
+    assert_eq!(s.rotate_right(0), s);
+    assert_eq!(s.rotate_left(s.len()), s);
+    assert_eq!(s.rotate_right(s.len()), s);
+    assert_eq!(s.rotate_left(1), "bcdefgha");
+    assert_eq!(s.rotate_right(1), "habcdefg");
+    assert_eq!(s.rotate_left(s.len() + 1), s.rotate_left(1));
+
+    for text in &["a", "ab", "abc", "abcdefg"] {
+        let s: TinyStr8 = text.parse().unwrap();
+        let mut expected = text.as_bytes().to_vec();
+        expected.rotate_left(1);
+        assert_eq!(s.rotate_left(1).as_bytes(), &expected[..]);
+        for n in 0..=2 * s.len() {
+            assert_eq!(s.rotate_left(n).rotate_right(n), s);
+            assert_eq!(s.rotate_right(n).rotate_left(n), s);
+        }
+    }
+}
+
+#[test]
+fn tiny8_subtags_rfc5646() {
+    // Examples from RFC 5646, appendix A. Each starts with a language
+    // subtag, and every subtag that fits has the shape of some kind.
+    for tag in [
+        "de",
+        "zh-Hant",
+        "zh-cmn-Hans-CN",
+        "sr-Latn-RS",
+        "sl-nedis",
+        "de-CH-1901",
+        "hy-Latn-IT-arevela",
+        "es-419",
+        "sl-IT-rozaj-1994",
+    ]
+    .iter()
+    {
+        let mut subtags = tag.split('-').filter_map(|s| s.parse::<TinyStr8>().ok());
+        assert!(subtags.next().unwrap().is_language_subtag(), "{}", tag);
+        for s in subtags {
+            let kinds = [
+                s.is_language_subtag(),
+                s.is_script_subtag(),
+                s.is_region_subtag(),
+                s.is_variant_subtag(),
+            ];
+            assert!(kinds.iter().any(|k| *k), "{} in {}", s, tag);
+        }
+    }
+
+    let valid = |s: &str| s.parse::<TinyStr8>().unwrap();
+    assert!(valid("1901").is_variant_subtag());
+    assert!(valid("1994").is_variant_subtag());
+    assert!(!valid("abcd").is_variant_subtag());
+    assert!(!valid("a901").is_variant_subtag());
+    assert!(!valid("190").is_variant_subtag());
+    assert!(!valid("19-1").is_variant_subtag());
+    assert!(!valid("1901").is_script_subtag());
+    assert!(!valid("Lat1").is_script_subtag());
+    assert!(!valid("e4").is_language_subtag());
+    assert!(!valid("e4").is_region_subtag());
+    assert!(!valid("1A").is_region_subtag());
+
+    assert!(valid("rozaj").is_variant_subtag());
+    assert!(valid("biske").is_variant_subtag());
+    assert!(valid("arevela").is_variant_subtag());
+    assert!(valid("1606nict").is_variant_subtag());
+    assert!(!valid("rozaj").is_script_subtag());
+    assert!(!valid("arevela").is_region_subtag());
+    assert!(!valid("1606nict").is_language_subtag());
+    assert!(!valid("x-abc").is_variant_subtag());
+}
+
+#[test]
+fn tiny8_strip_suffix_str() {
+    let s: TinyStr8 = "en-posix".parse().unwrap();
+    assert_eq!(s.strip_suffix_str("-posix"), Some("en"));
+    assert_eq!(s.strip_suffix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_suffix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_suffix_str("-POSIX"), None);
+    assert_eq!(s.strip_suffix_str("en"), None);
+    assert_eq!(s.strip_suffix_str("ien-posix"), None);
+    let x: TinyStr8 = "x".parse().unwrap();
+    assert_eq!(x.strip_suffix_str("x"), Some(""));
+    assert_eq!(x.strip_suffix_str("xx"), None);
+}
+
+#[test]
+fn tiny8_with_prefix_suffix() {
+    let s: TinyStr8 = "abcdefg".parse().unwrap();
+    let full = s.with_suffix('z').unwrap();
+    assert_eq!(full, "abcdefgz");
+    assert_eq!(s.with_prefix('z').unwrap(), "zabcdefg");
+    assert_eq!(
+        full.with_suffix('z'),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    assert_eq!(
+        full.with_prefix('z'),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+
+    let a: TinyStr8 = "a".parse().unwrap();
+    assert_eq!(a.with_prefix('-').unwrap(), "-a");
+    assert_eq!(a.with_suffix('-').unwrap(), "a-");
+    assert_eq!(a.with_prefix('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(a.with_suffix('é'), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(a.with_prefix('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert_eq!(a.with_suffix('\0'), Err(Error::InvalidNull { index: 1 }));
+}
+
+#[test]
+fn tiny8_to_u64_radix() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    assert_eq!(parse("ff").to_u64_radix(16), Some(255));
+    assert_eq!(parse("FF").to_u64_radix(16), Some(255));
+    assert_eq!(parse("101").to_u64_radix(2), Some(5));
+    assert_eq!(parse("102").to_u64_radix(2), None);
+    assert_eq!(parse("zz").to_u64_radix(36), Some(36 * 36 - 1));
+    assert_eq!(parse("-1").to_u64_radix(10), None);
+    assert_eq!(parse("+1").to_u64_radix(10), None);
+    assert_eq!(parse("ffffffff").to_u64_radix(16), Some(0xffff_ffff));
+    assert_eq!(parse("zzzzzzzz").to_u64_radix(36), Some(36u64.pow(8) - 1));
+
+    for text in &["0", "7", "42", "419", "20241231", "ab", "x1"] {
+        for radix in &[2, 8, 10, 16, 36] {
+            assert_eq!(
+                parse(text).to_u64_radix(*radix),
+                u64::from_str_radix(text, *radix).ok(),
+                "{} in radix {}",
+                text,
+                radix
+            );
+        }
+        assert_eq!(parse(text).to_u64_radix(10), parse(text).to_u64());
+    }
+}
+
+#[test]
+#[should_panic]
+fn tiny8_to_u64_radix_bad_radix() {
+    let _ = "1".parse::<TinyStr8>().unwrap().to_u64_radix(37);
+}
+
+#[test]
+fn tiny8_common_prefix_len() {
+    let parse = |s: &str| s.parse::<TinyStr8>().unwrap();
+    assert_eq!(parse("enx").common_prefix_len(&parse("eny")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("abc")), 3);
+    assert_eq!(parse("ab").common_prefix_len(&parse("xy")), 0);
+    assert_eq!(parse("ab").common_prefix_len(&parse("abc")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("ab")), 2);
+    assert_eq!(parse("ab").common_prefix_len(&parse("aB")), 1);
+
+    let full = parse("abcdefgh");
+    assert_eq!(full.common_prefix_len(&full), 8);
+    for text in &["a", "ab", "abx", "abcdefgx", "b"] {
+        let s = parse(text);
+        let expected = full
+            .bytes()
+            .zip(s.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        assert_eq!(full.common_prefix_len(&s), expected, "{}", text);
+        assert_eq!(s.common_prefix_len(&full), expected, "{}", text);
+    }
+}
+
+#[test]
+fn tiny8_char_predicates() {
+    let s: TinyStr8 = "en-US-1".parse().unwrap();
+    assert!(s.all_chars(|c| c.is_ascii_graphic()));
+    assert!(!s.all_chars(|c| c.is_ascii_alphabetic()));
+    assert!(s.any_char(|c| c.is_ascii_digit()));
+    assert!(!s.any_char(|c| c == ' '));
+    assert_eq!(
+        s.all_chars(char::is_alphanumeric),
+        s.chars().all(char::is_alphanumeric)
+    );
+
+    assert_eq!(s.map_chars(|c| c), Ok(s));
+    assert_eq!(
+        s.map_chars(|c| c.to_ascii_uppercase()),
+        Ok(s.to_ascii_uppercase())
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { '_' } else { c }).unwrap(),
+        "en_US_1"
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { 'é' } else { c }),
+        Err(Error::NonAscii { index: 2 })
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == 'n' { '\0' } else { c }),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny8_from_decimal() {
+    for n in [
+        0,
+        9,
+        10,
+        99,
+        100,
+        419,
+        999,
+        1000,
+        9999,
+        99_999_999,
+        100_000_000,
+        u32::MAX,
+    ]
+    .iter()
+    {
+        let expected = n.to_string();
+        match TinyStr8::from_decimal(*n) {
+            Ok(s) => {
+                assert_eq!(s, expected.as_str());
+                assert_eq!(s.to_u64(), Some(u64::from(*n)));
+            }
+            Err(err) => {
+                assert!(expected.len() > 8, "{}", n);
+                assert_eq!(
+                    err,
+                    Error::InvalidSize {
+                        len: expected.len(),
+                        max: 8
+                    }
+                );
+            }
+        }
+    }
+
+    assert_eq!(TinyStr8::from_decimal(0).unwrap(), "0");
+    assert_eq!(TinyStr8::from_decimal_padded(7, 3).unwrap(), "007");
+    assert_eq!(TinyStr8::from_decimal_padded(0, 2).unwrap(), "00");
+    assert_eq!(TinyStr8::from_decimal_padded(0, 0).unwrap(), "0");
+    assert_eq!(TinyStr8::from_decimal_padded(419, 2).unwrap(), "419");
+    assert_eq!(TinyStr8::from_decimal_padded(1, 8).unwrap().len(), 8);
+    assert_eq!(
+        TinyStr8::from_decimal_padded(1, 9),
+        Err(Error::InvalidSize { len: 9, max: 8 })
+    );
+    for width in 0..=8 {
+        let s = TinyStr8::from_decimal_padded(42, width).unwrap();
+        assert_eq!(s, format!("{:0width$}", 42, width = width).as_str());
+        assert_eq!(s.to_u64(), Some(42));
+    }
+}
+
+#[test]
+fn tiny16_size() {
+    assert_eq!(
+        "".parse::<TinyStr16>(),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert!("1".parse::<TinyStr16>().is_ok());
+    assert!("12".parse::<TinyStr16>().is_ok());
+    assert!("123".parse::<TinyStr16>().is_ok());
+    assert!("1234".parse::<TinyStr16>().is_ok());
+    assert!("12345".parse::<TinyStr16>().is_ok());
+    assert!("123456".parse::<TinyStr16>().is_ok());
+    assert!("1234567".parse::<TinyStr16>().is_ok());
+    assert!("12345678".parse::<TinyStr16>().is_ok());
+    assert!("123456781".parse::<TinyStr16>().is_ok());
+    assert!("1234567812".parse::<TinyStr16>().is_ok());
+    assert!("12345678123".parse::<TinyStr16>().is_ok());
+    assert!("123456781234".parse::<TinyStr16>().is_ok());
+    assert!("1234567812345".parse::<TinyStr16>().is_ok());
+    assert!("12345678123456".parse::<TinyStr16>().is_ok());
+    assert!("123456781234567".parse::<TinyStr16>().is_ok());
+    assert!("1234567812345678".parse::<TinyStr16>().is_ok());
+    assert_eq!(
+        "12345678123456789".parse::<TinyStr16>(),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+}
+
+#[test]
+fn tiny16_null() {
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr16>(),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        "ab\u{0}d".parse::<TinyStr16>(),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny16_new_unchecked() {
+    let reference: TinyStr16 = "WindowsCE/ME/NT".parse().unwrap();
+    let uval: u128 = reference.into();
+    let s = unsafe { TinyStr16::new_unchecked(uval) };
+    assert_eq!(s, reference);
+    assert_eq!(s, "WindowsCE/ME/NT");
+}
+
+#[test]
+fn tiny16_nonascii() {
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr16>(),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        "abcé".parse::<TinyStr16>(),
+        Err(Error::NonAscii { index: 3 })
+    );
+}
+
+#[test]
+fn tiny16_alpha() {
+    let s: TinyStr16 = "@abcdefgTUVWXYZ[".parse().unwrap();
+    assert!(!s.is_ascii_alphanumeric());
+    assert_eq!(s.to_ascii_uppercase().as_str(), "@ABCDEFGTUVWXYZ[");
+    assert_eq!(s.to_ascii_lowercase().as_str(), "@abcdefgtuvwxyz[");
+
+    assert!("abcdefgTUVWXYZ"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+}
+
+#[test]
+fn tiny16_titlecase() {
+    assert_eq!(
+        "abcdabcdabcdabcd"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcdabcdabcd"
+    );
+    assert_eq!(
+        "ABCDABCDABCDABCD"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcdabcdabcd"
+    );
+    assert_eq!(
+        "aBCDaBCDaBCDaBCD"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "Abcdabcdabcdabcd"
+    );
+    assert_eq!(
+        "A123a123A123a123"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "A123a123a123a123"
+    );
+    assert_eq!(
+        "123a123A123a123A"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase()
+            .as_str(),
+        "123a123a123a123a"
+    );
+}
+
+#[test]
+fn tiny16_ord() {
+    let mut v: Vec<TinyStr16> = vec!["nedis_xxxx".parse().unwrap(), "macos_xxxx".parse().unwrap()];
+    v.sort();
+
+    assert_eq!(v.get(0).unwrap().as_str(), "macos_xxxx");
+    assert_eq!(v.get(1).unwrap().as_str(), "nedis_xxxx");
+}
+
+#[test]
+fn tiny16_eq() {
+    let s1: TinyStr16 = "windows98SE".parse().unwrap();
+    let s2: TinyStr16 = "mac".parse().unwrap();
+    let s3: TinyStr16 = "windows98SE".parse().unwrap();
+
+    assert_eq!(s1, s3);
+    assert_ne!(s1, s2);
+}
+
+#[test]
+fn tiny16_display() {
+    let s: TinyStr16 = "abcdefghijkl".parse().unwrap();
+    let mut result = String::new();
+    write!(result, "{}", s).unwrap();
+    assert_eq!(result, "abcdefghijkl");
+    assert_eq!(format!("{}", s), "abcdefghijkl");
+}
+
+#[test]
+fn tiny16_debug() {
     let s: TinyStr16 = "abcdefghijkl".parse().unwrap();
     assert_eq!(format!("{:#?}", s), "\"abcdefghijkl\"");
 }
+
+#[test]
+fn tiny16_titlecase_words() {
+    assert_eq!(
+        "new_york-en us"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "New_York-En Us"
+    );
+    assert_eq!(
+        "EN-US_pOSIX"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "En-Us_Posix"
+    );
+    assert_eq!(
+        "-ab CD_1a"
+            .parse::<TinyStr16>()
+            .unwrap()
+            .to_ascii_titlecase_words()
+            .as_str(),
+        "-Ab Cd_1a"
+    );
+}
+
+#[test]
+fn tiny16_alphabetic() {
+    assert!("abcdefgTUVWXYZ"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_alphabetic());
+    assert!(!"abc1".parse::<TinyStr16>().unwrap().is_ascii_alphabetic());
+    assert!(!"@az[".parse::<TinyStr16>().unwrap().is_ascii_alphabetic());
+    assert!(!"`az{".parse::<TinyStr16>().unwrap().is_ascii_alphabetic());
+
+    assert!("abc1".parse::<TinyStr16>().unwrap().is_ascii_alphanumeric());
+    assert!("A123a123A123a123"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+    assert!("0123456789012345"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_alphanumeric());
+    assert!(!"a/9:".parse::<TinyStr16>().unwrap().is_ascii_alphanumeric());
+}
+
+#[test]
+fn tiny16_len() {
+    assert_eq!(TinyStr16::CAPACITY, 16);
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr16::CAPACITY {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert_eq!(s.len(), len);
+        assert_eq!(s.len(), s.as_str().len());
+        assert_eq!(s.capacity(), 16);
+        assert!(s.len() <= s.capacity());
+        assert!(!s.is_empty());
+    }
+}
+
+#[test]
+fn tiny16_as_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr16::CAPACITY {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert_eq!(s.as_bytes().len(), s.len());
+        assert_eq!(s.as_bytes(), s.as_str().as_bytes());
+        assert_eq!(AsRef::<[u8]>::as_ref(&s), &text.as_bytes()[..len]);
+    }
+}
+
+#[test]
+fn tiny16_numeric() {
+    assert!("419".parse::<TinyStr16>().unwrap().is_ascii_numeric());
+    assert!("0012345678901234"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_numeric());
+    assert!(!"4a9".parse::<TinyStr16>().unwrap().is_ascii_numeric());
+    assert!(!"41.9".parse::<TinyStr16>().unwrap().is_ascii_numeric());
+}
+
+#[test]
+fn tiny16_trim_ascii() {
+    let s: TinyStr16 = "   abc de fghi".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "abc de fghi");
+    assert_eq!(s.trim_ascii_end().unwrap(), s);
+    assert_eq!(s.trim_ascii().unwrap(), "abc de fghi");
+
+    let s: TinyStr16 = "abc de fghi    ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), s);
+    assert_eq!(s.trim_ascii_end().unwrap(), "abc de fghi");
+    assert_eq!(s.trim_ascii().unwrap(), "abc de fghi");
+
+    let s: TinyStr16 = "  a b c d e f  ".parse().unwrap();
+    assert_eq!(s.trim_ascii_start().unwrap(), "a b c d e f  ");
+    assert_eq!(s.trim_ascii_end().unwrap(), "  a b c d e f");
+    assert_eq!(s.trim_ascii().unwrap(), "a b c d e f");
+
+    let s: TinyStr16 = "abcdefghijklmnop".parse().unwrap();
+    assert_eq!(s.trim_ascii().unwrap(), s);
+
+    let s: TinyStr16 = "                ".parse().unwrap();
+    assert_eq!(
+        s.trim_ascii_start(),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(
+        s.trim_ascii_end(),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(s.trim_ascii(), Err(Error::InvalidSize { len: 0, max: 16 }));
+}
+
+#[test]
+fn tiny16_bytes() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr16::CAPACITY {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        let bytes = s.to_bytes();
+        assert_eq!(&bytes[..len], s.as_bytes());
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(bytes, u128::from(s).to_le_bytes());
+        assert_eq!(TinyStr16::from_bytes_padded(bytes), Ok(s));
+    }
+
+    let mut bytes = [0; 16];
+    assert_eq!(
+        TinyStr16::from_bytes_padded(bytes),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    bytes[0] = b'a';
+    bytes[2] = b'b';
+    assert_eq!(
+        TinyStr16::from_bytes_padded(bytes),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    bytes[1] = 0xe4;
+    assert_eq!(
+        TinyStr16::from_bytes_padded(bytes),
+        Err(Error::NonAscii { index: 1 })
+    );
+}
+
+#[test]
+fn tiny16_case() {
+    let s: TinyStr16 = "ABC12@[`{DEF".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+
+    let s: TinyStr16 = "abc12@[`{def".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+
+    let s: TinyStr16 = "Abc".parse().unwrap();
+    assert!(!s.is_ascii_uppercase());
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_mixed_case());
+
+    let s: TinyStr16 = "12-@".parse().unwrap();
+    assert!(s.is_ascii_uppercase());
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_mixed_case());
+}
+
+#[test]
+fn tiny16_replace_char() {
+    let s: TinyStr16 = "zh-hant-tw-x-a".parse().unwrap();
+    assert_eq!(s.replace_char('-', '_').unwrap(), "zh_hant_tw_x_a");
+    assert_eq!(s.replace_char('e', 'E').unwrap(), "zh-hant-tw-x-a");
+    assert_eq!(s.replace_char('$', '_').unwrap(), s);
+    assert_eq!(s.replace_char('\0', '_').unwrap(), s);
+    assert_eq!(s.replace_char('é', '_').unwrap(), s);
+    assert_eq!(s.replace_char('-', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(
+        s.replace_char('-', '\0'),
+        Err(Error::InvalidNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny16_bytes_iter() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=TinyStr16::CAPACITY {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert_eq!(s.bytes().len(), len);
+        assert!(s.bytes().eq(text[..len].bytes()));
+        assert!(s.bytes().rev().eq(s.as_str().bytes().rev()));
+    }
+
+    let s: TinyStr16 = "abc".parse().unwrap();
+    let mut bytes = s.bytes();
+    assert_eq!(bytes.next(), Some(b'a'));
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes.next_back(), Some(b'c'));
+    assert_eq!(bytes.clone().next(), Some(b'b'));
+    assert_eq!(bytes.next_back(), Some(b'b'));
+    assert_eq!(bytes.next(), None);
+    assert_eq!(bytes.next_back(), None);
+}
+
+#[test]
+fn tiny16_hexdigit() {
+    assert!("0123456789abcDEF"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_hexdigit());
+    assert!("12ff".parse::<TinyStr16>().unwrap().is_ascii_hexdigit());
+    assert!(!"xyz".parse::<TinyStr16>().unwrap().is_ascii_hexdigit());
+    assert!(!"12g4".parse::<TinyStr16>().unwrap().is_ascii_hexdigit());
+    assert!(!"12G4".parse::<TinyStr16>().unwrap().is_ascii_hexdigit());
+    assert!(!"@`/:".parse::<TinyStr16>().unwrap().is_ascii_hexdigit());
+}
+
+#[test]
+fn tiny16_swapcase() {
+    let s: TinyStr16 = "HelloWorld1@[`x".parse().unwrap();
+    assert_eq!(s.to_ascii_swapcase(), "hELLOwORLD1@[`X");
+    assert_eq!(s.to_ascii_swapcase().to_ascii_swapcase(), s);
+}
+
+#[test]
+fn tiny16_graphic() {
+    assert_eq!(
+        TinyStr16::from_str_graphic("hello, world~!"),
+        Ok("hello, world~!".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr16::from_str_graphic("a\tb"),
+        Err(Error::NonGraphic { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_graphic("ab\u{7f}"),
+        Err(Error::NonGraphic { index: 2 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_graphic("\u{1}"),
+        Err(Error::NonGraphic { index: 0 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_graphic("a\u{0}b"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert!("a\tb".parse::<TinyStr16>().is_ok());
+}
+
+#[test]
+fn tiny16_chars() {
+    let text = "aB3-@[`{ ~z0Z9_.";
+    for len in 1..=TinyStr16::CAPACITY {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert!(s.chars().eq(text[..len].chars()));
+        assert!(s.chars().rev().eq(text[..len].chars().rev()));
+        assert_eq!(s.chars().len(), len);
+        assert_eq!(s.chars().size_hint(), (len, Some(len)));
+        let mut chars = s.chars();
+        chars.next();
+        assert_eq!(chars.size_hint(), (len - 1, Some(len - 1)));
+        assert!(chars.clone().eq(text[1..len].chars()));
+    }
+}
+
+#[test]
+fn tiny16_titlecase_all_words() {
+    let s: TinyStr16 = "new yORK-city".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), "New York-city");
+    let s: TinyStr16 = "newyork_city".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), s.to_ascii_titlecase());
+    let s: TinyStr16 = " new york city".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), " New York City");
+    let s: TinyStr16 = " new york city ".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_all_words(), " New York City ");
+}
+
+#[test]
+fn tiny16_option_size() {
+    assert_eq!(size_of::<TinyStr16>(), 16);
+    assert_eq!(size_of::<Option<TinyStr16>>(), size_of::<TinyStr16>());
+}
+
+#[test]
+fn tiny16_get() {
+    let s: TinyStr16 = "zh-Hant-TW".parse().unwrap();
+    assert_eq!(s.get(0), Some('z'));
+    assert_eq!(s.get(1), Some('h'));
+    assert_eq!(s.get(s.len() - 1), Some('W'));
+    assert_eq!(s.get(s.len()), None);
+    assert_eq!(s.get(usize::MAX), None);
+    assert_eq!(s.first(), 'z');
+    assert_eq!(s.last(), 'W');
+    for (i, c) in s.as_str().chars().enumerate() {
+        assert_eq!(s.get(i), Some(c));
+        assert!(s.get(i).unwrap().is_ascii());
+    }
+
+    let s: TinyStr16 = "abcdefghijklmnop".parse().unwrap();
+    assert_eq!(s.get(16 - 1), Some('p'));
+    assert_eq!(s.get(16), None);
+    assert_eq!(s.last(), 'p');
+
+    let s: TinyStr16 = "x".parse().unwrap();
+    assert_eq!(s.first(), 'x');
+    assert_eq!(s.last(), 'x');
+}
+
+#[test]
+fn tiny16_radix_fmt() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    assert_eq!(format!("{:x}", s), "6e65");
+    assert_eq!(format!("{:X}", s), "6E65");
+    assert_eq!(format!("{:#010x}", s), "0x00006e65");
+    assert_eq!(format!("{:b}", s), "110111001100101");
+    assert_eq!(format!("{:o}", s), "67145");
+
+    let s: TinyStr16 = "WindowsCE/ME/NT".parse().unwrap();
+    let word = u128::from(s);
+    assert_eq!(format!("{:x}", s), format!("{:x}", word));
+    assert_eq!(format!("{:X}", s), format!("{:X}", word));
+    assert_eq!(format!("{:b}", s), format!("{:b}", word));
+    assert_eq!(format!("{:o}", s), format!("{:o}", word));
+}
+
+#[test]
+fn tiny16_index() {
+    let s: TinyStr16 = "abcdefghijklmnop".parse().unwrap();
+    assert_eq!(&s[1..3], "bc");
+    assert_eq!(&s[..2], "ab");
+    assert_eq!(&s[14..], "op");
+    assert_eq!(&s[..], s.as_str());
+    assert_eq!(&s[16..], "");
+    assert_eq!(&s[1..1], "");
+}
+
+#[test]
+#[should_panic]
+fn tiny16_index_out_of_range() {
+    let s: TinyStr16 = "abc".parse().unwrap();
+    let _ = &s[1..4];
+}
+
+#[test]
+fn tiny16_starts_ends_with() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr16 = text.parse().unwrap();
+    for len in 0..=16 {
+        assert!(s.starts_with(&text[..len]));
+        assert!(s.ends_with(&text[len..]));
+    }
+    for pat in &["", "a", "b", "ab", "ba", "op", "abcdefghijklmnopq", "é"] {
+        assert_eq!(s.starts_with(pat), text.starts_with(pat));
+        assert_eq!(s.ends_with(pat), text.ends_with(pat));
+    }
+    assert!(s.starts_with(String::from("ab")));
+    assert!(s.starts_with_char('a'));
+    assert!(!s.starts_with_char('b'));
+    assert!(s.ends_with_char('p'));
+    assert!(!s.ends_with_char('a'));
+    assert!(!s.ends_with_char('é'));
+
+    let s: TinyStr16 = "x".parse().unwrap();
+    assert!(s.starts_with("x"));
+    assert!(s.ends_with("x"));
+    assert!(!s.starts_with("xy"));
+    assert!(!s.ends_with("yx"));
+    assert!(s.starts_with_char('x'));
+    assert!(s.ends_with_char('x'));
+}
+
+#[test]
+fn tiny16_from_str_lossy() {
+    assert_eq!(
+        TinyStr16::from_str_lossy("zh-Hant-TW", b'?').unwrap(),
+        "zh-Hant-TW"
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy("café-crème", b'?').unwrap(),
+        "caf??-cr??me"
+    );
+    assert_eq!(TinyStr16::from_str_lossy("\u{4000}", b'_').unwrap(), "___");
+    assert_eq!(
+        TinyStr16::from_str_lossy("", b'?'),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy("abcdefghijklmnopé", b'?'),
+        Err(Error::InvalidSize { len: 18, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy("a\u{0}é", b'?'),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy("é", 0),
+        Err(Error::InvalidNull { index: 0 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy("é", 0xbf),
+        Err(Error::NonAscii { index: 0 })
+    );
+}
+
+#[test]
+fn tiny16_repeat_char() {
+    assert_eq!(TinyStr16::repeat_char('x', 1).unwrap(), "x");
+    assert_eq!(TinyStr16::repeat_char('x', 3).unwrap(), "xxx");
+    assert_eq!(
+        TinyStr16::repeat_char('-', 16).unwrap(),
+        "-".repeat(16).as_str()
+    );
+    assert_eq!(
+        TinyStr16::repeat_char('x', 0),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::repeat_char('x', 17),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::repeat_char('é', 2),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr16::repeat_char('\0', 2),
+        Err(Error::InvalidNull { index: 0 })
+    );
+}
+
+#[test]
+fn tiny16_strip_prefix_suffix() {
+    let s: TinyStr16 = "x-private-use".parse().unwrap();
+    assert_eq!(s.strip_prefix("x-").unwrap(), "private-use");
+    assert_eq!(s.strip_prefix("").unwrap(), s);
+    assert_eq!(s.strip_prefix("y-"), None);
+    assert_eq!(s.strip_prefix("x-p"), Some("rivate-use".parse().unwrap()));
+    assert_eq!(s.strip_prefix(s.as_str()), None);
+    assert_eq!(s.strip_prefix("x-private-user"), None);
+
+    assert_eq!(s.strip_suffix("use").unwrap(), "x-private-");
+    assert_eq!(s.strip_suffix("").unwrap(), s);
+    assert_eq!(s.strip_suffix("x"), None);
+    assert_eq!(s.strip_suffix(s.as_str()), None);
+    assert_eq!(s.strip_suffix("zx-private-use"), None);
+}
+
+#[test]
+fn tiny16_from_str_truncating() {
+    let text = "abcdefghijklmnop";
+    assert_eq!(TinyStr16::from_str_truncating(text).unwrap(), text);
+    assert_eq!(
+        TinyStr16::from_str_truncating("abcdefghijklmnopq").unwrap(),
+        text
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("abcdefghijklmnopqrstuvwxyz").unwrap(),
+        text
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("en-US-posix-u-cach").unwrap(),
+        "en-US-posix-u-ca"
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("abcdefghijklmnopé").unwrap(),
+        text
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("ab"),
+        Ok("ab".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating(""),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("aécdefghijklmnop"),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_truncating("a\u{0}cdefghijklmnop"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny16_contains() {
+    let alphabet = ["", "a", "b", "-", "é"];
+    let mut needles: Vec<String> = vec![];
+    for x in &alphabet {
+        for y in &alphabet {
+            for z in &alphabet {
+                needles.push(format!("{}{}{}", x, y, z));
+            }
+        }
+    }
+    for text in &["a", "ab", "ba", "a-b", "ab-ba-aab-bba-a"] {
+        let s: TinyStr16 = text.parse().unwrap();
+        for needle in &needles {
+            assert_eq!(s.contains(needle), text.contains(needle.as_str()));
+        }
+        for i in 0..text.len() {
+            for j in i..=text.len() {
+                assert!(s.contains(&text[i..j]));
+            }
+        }
+        assert!(!s.contains(&format!("{}a", text)));
+    }
+}
+
+#[test]
+fn tiny16_replace() {
+    let s: TinyStr16 = "en_us_x_posix".parse().unwrap();
+    assert_eq!(s.replace('_', '-').unwrap(), "en-us-x-posix");
+    assert_eq!(s.replace('#', '-').unwrap(), s);
+    assert_eq!(s.replace('\0', '-').unwrap(), s);
+    assert_eq!(s.replace('é', '-'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(s.replace('_', 'é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.replace('_', '\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny16_buf() {
+    let text = "abcdefghijklmnop";
+    let mut buf = TinyStrBuf16::new();
+    assert!(buf.is_empty());
+    assert_eq!(buf.pop(), None);
+    assert_eq!(buf.build(), Err(Error::InvalidSize { len: 0, max: 16 }));
+    for (i, c) in text[..16].chars().enumerate() {
+        buf.push(c).unwrap();
+        assert_eq!(buf.len(), i + 1);
+        assert_eq!(&*buf, &text[..=i]);
+    }
+    assert_eq!(buf.push('x'), Err(Error::InvalidSize { len: 17, max: 16 }));
+    assert_eq!(buf.as_str(), &text[..16]);
+    assert_eq!(buf.build().unwrap(), &text[..16]);
+
+    assert_eq!(buf.pop(), Some('p'));
+    assert_eq!(format!("{}", buf), &text[..15]);
+    assert_eq!(format!("{:?}", buf), format!("{:?}", &text[..15]));
+    buf.clear();
+    assert!(buf.is_empty());
+    assert_eq!(buf.push('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(buf.push('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert!(buf.is_empty());
+
+    let s: TinyStr16 = "en".parse().unwrap();
+    let mut buf = TinyStrBuf16::from(s);
+    assert_eq!(buf.len(), 2);
+    buf.push('-').unwrap();
+    assert_eq!(TinyStr16::from(buf), "en-");
+    assert_eq!(buf.pop(), Some('-'));
+    assert_eq!(TinyStr16::from(buf), s);
+}
+
+#[test]
+fn tiny16_find() {
+    let text = "a-b-c-d-e-f-g-h-";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        for c in &['a', 'b', '-', 'z', '\0', 'é', '\u{80}'] {
+            assert_eq!(s.find(*c), text[..len].find(*c));
+            assert_eq!(s.rfind(*c), text[..len].rfind(*c));
+        }
+    }
+    let s: TinyStr16 = "x".parse().unwrap();
+    assert_eq!(s.find('x'), Some(0));
+    assert_eq!(s.rfind('x'), Some(0));
+}
+
+#[test]
+fn tiny16_byte_at() {
+    let s: TinyStr16 = "a-Z9@[`{ ~_.!zA0".parse().unwrap();
+    for i in 0..s.len() {
+        assert_eq!(s.byte_at(i), Some(s.as_bytes()[i]));
+        assert_eq!(s.get(i), s.as_str().chars().nth(i));
+    }
+    assert_eq!(s.byte_at(s.len()), None);
+    assert_eq!(s.get(s.len()), None);
+}
+
+#[test]
+fn tiny16_contains_char() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        for c in "\0 -_aehmpqzA~é\u{80}\u{7f}\u{4000}".chars() {
+            assert_eq!(
+                s.contains_char(c),
+                s.as_str().contains(c),
+                "{:?} {:?}",
+                s,
+                c
+            );
+        }
+        for b in 0..=255u8 {
+            assert_eq!(
+                s.contains_byte(b),
+                s.as_bytes().contains(&b),
+                "{:?} {}",
+                s,
+                b
+            );
+        }
+    }
+    let s: TinyStr16 = "a-b".parse().unwrap();
+    assert!(s.contains_char('-'));
+    assert!(!s.contains_char('_'));
+}
+
+#[test]
+fn tiny16_from_iter() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr16 = text[..16].chars().collect();
+    assert_eq!(s, &text[..16]);
+    let s: TinyStr16 = "the quick fox"
+        .split(' ')
+        .filter_map(|w| w.chars().next())
+        .collect();
+    assert_eq!(s, "tqf");
+
+    let c: TinyStr16Collector = text[..16].chars().collect();
+    assert_eq!(c.try_build().unwrap(), &text[..16]);
+    let c: TinyStr16Collector = text[..=16].chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidSize { len: 17, max: 16 }));
+    let c: TinyStr16Collector = "aébc".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 1 }));
+    let c: TinyStr16Collector = "a\0".chars().collect();
+    assert_eq!(c.try_build(), Err(Error::InvalidNull { index: 1 }));
+    assert_eq!(
+        TinyStr16Collector::new().try_build(),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+
+    let mut c = TinyStr16Collector::new();
+    c.extend("ab".chars());
+    c.extend("é".chars());
+    c.extend("c".chars());
+    assert_eq!(c.try_build(), Err(Error::NonAscii { index: 2 }));
+}
+
+#[test]
+#[should_panic]
+fn tiny16_from_iter_overflow() {
+    let _: TinyStr16 = "abcdefghijklmnopq"[..=16].chars().collect();
+}
+
+#[test]
+fn tiny16_from_str_lossy_truncating() {
+    assert_eq!(
+        TinyStr16::from_str_lossy_truncating("metaMoRphosis123456").unwrap(),
+        "metaMoRphosis123"
+    );
+    assert_eq!(
+        TinyStr16::from_str_lossy_truncating("a\0é\u{7f}").unwrap(),
+        "a???\u{7f}"
+    );
+    assert_eq!(TinyStr16::from_str_lossy_truncating(""), None);
+}
+
+#[test]
+fn tiny16_split() {
+    for text in &[
+        "a",
+        "-",
+        "a-b",
+        "-a",
+        "a-",
+        "--",
+        "a--b",
+        "-a-b-",
+        "ab-cd",
+        "zh-Hant-TW-x-abc",
+    ] {
+        let s: TinyStr16 = text.parse().unwrap();
+        let expected: Vec<&str> = text.split('-').filter(|seg| !seg.is_empty()).collect();
+        assert_eq!(s.split('-').collect::<Vec<_>>(), expected, "{:?}", text);
+    }
+    let s: TinyStr16 = "a-b".parse().unwrap();
+    assert_eq!(s.split('_').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('é').collect::<Vec<_>>(), vec![s]);
+    assert_eq!(s.split('\0').collect::<Vec<_>>(), vec![s]);
+    let mut it = s.split('-');
+    assert_eq!(it.next().unwrap(), "a");
+    assert_eq!(it.next().unwrap(), "b");
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn tiny16_hash() {
+    let text = "metamorphosis";
+    let s: TinyStr16 = text.parse().unwrap();
+    let mut h1 = DefaultHasher::new();
+    text.hash(&mut h1);
+    let mut h2 = DefaultHasher::new();
+    s.hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
+
+    let mut map = HashMap::new();
+    map.insert(s, 1);
+    assert_eq!(map.get(text), Some(&1));
+    assert_eq!(map.get("other"), None);
+}
+
+#[test]
+fn tiny16_split_once() {
+    let s: TinyStr16 = "zh-Hant-TW".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "zh");
+    assert_eq!(tail, "Hant-TW");
+    assert_eq!(s.split_once('_'), None);
+    assert_eq!(s.split_once('é'), None);
+    let s: TinyStr16 = "-en".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr16 = "en-".parse().unwrap();
+    assert_eq!(s.split_once('-'), None);
+    let s: TinyStr16 = "a-b-c-d-e-f-g-h".parse().unwrap();
+    let (head, tail) = s.split_once('-').unwrap();
+    assert_eq!(head, "a");
+    assert_eq!(tail, "b-c-d-e-f-g-h");
+}
+
+#[test]
+fn tiny16_split_at() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr16 = text[..16].parse().unwrap();
+    for idx in 1..16 {
+        let (head, tail) = s.split_at(idx);
+        assert_eq!(head, &text[..idx]);
+        assert_eq!(tail.unwrap(), &text[idx..16]);
+    }
+    assert_eq!(s.split_at(16), (s, None));
+    let s: TinyStr16 = "ab".parse().unwrap();
+    assert_eq!(s.split_at(2), (s, None));
+}
+
+#[test]
+#[should_panic]
+fn tiny16_split_at_zero() {
+    let s: TinyStr16 = "ab".parse().unwrap();
+    s.split_at(0);
+}
+
+#[test]
+#[should_panic]
+fn tiny16_split_at_out_of_bounds() {
+    let s: TinyStr16 = "ab".parse().unwrap();
+    s.split_at(3);
+}
+
+#[test]
+fn tiny16_replace_matches_str() {
+    let text = "a-b_c-de--f_gh-i";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        for &(from, to) in &[('-', '_'), ('_', '-'), ('a', 'A'), ('-', '-'), ('z', 'y')] {
+            let expected = text[..len].replace(from, to.encode_utf8(&mut [0; 4]));
+            assert_eq!(s.replace(from, to).unwrap(), expected.as_str());
+            assert_eq!(s.replace(from, to).unwrap().len(), len);
+        }
+    }
+}
+
+#[test]
+fn tiny16_into_string() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        let owned = String::from(s);
+        assert_eq!(owned, &text[..len]);
+        assert_eq!(owned.len(), len);
+        assert_eq!(owned, s.to_string());
+    }
+    let v: Vec<String> = vec!["metamorphosis".parse::<TinyStr16>().unwrap()]
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    assert_eq!(v, vec!["metamorphosis"]);
+}
+
+#[test]
+fn tiny16_cow() {
+    let s: TinyStr16 = "metamorphosis".parse().unwrap();
+    match Cow::from(&s) {
+        Cow::Borrowed(b) => assert_eq!(b, "metamorphosis"),
+        Cow::Owned(_) => panic!("expected a borrowed Cow"),
+    }
+    match Cow::from(s) {
+        Cow::Owned(o) => assert_eq!(o, "metamorphosis"),
+        Cow::Borrowed(_) => panic!("expected an owned Cow"),
+    }
+    let takes_cow = |c: Cow<str>| c.len();
+    assert_eq!(takes_cow((&s).into()), s.len());
+    assert_eq!(takes_cow(s.into()), s.len());
+}
+
+#[test]
+fn tiny16_reverse() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        let expected: String = text[..len].chars().rev().collect();
+        assert_eq!(s.reverse(), expected.as_str());
+        assert_eq!(s.reverse().len(), len);
+        assert_eq!(s.reverse().reverse(), s);
+    }
+    let s: TinyStr16 = "x".parse().unwrap();
+    assert_eq!(s.reverse(), s);
+    let s: TinyStr16 = "zh-Hant-TW".parse().unwrap();
+    assert_eq!(s.reverse(), "WT-tnaH-hz");
+}
+
+#[test]
+fn tiny16_try_from_chars() {
+    assert_eq!(
+        TinyStr16::try_from_chars(['e', 'n'].iter().copied()).unwrap(),
+        "en"
+    );
+    assert_eq!(
+        TinyStr16::try_from_chars("abcdefghijklmnop"[..16].chars()).unwrap(),
+        &"abcdefghijklmnop"[..16]
+    );
+    assert_eq!(
+        TinyStr16::try_from_chars("abcdefghijklmnopq"[..=16].chars()),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::try_from_chars("aé".chars()),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::try_from_chars(std::iter::empty()),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+}
+
+#[test]
+fn tiny16_repeat() {
+    let s: TinyStr16 = "ab".parse().unwrap();
+    assert_eq!(s.repeat(1).unwrap(), s);
+    assert_eq!(s.repeat(8).unwrap(), "ab".repeat(8).as_str());
+    assert_eq!(s.repeat(9), Err(Error::InvalidSize { len: 18, max: 16 }));
+    assert_eq!(s.repeat(0), Err(Error::InvalidSize { len: 0, max: 16 }));
+    assert_eq!(
+        s.repeat(usize::MAX),
+        Err(Error::InvalidSize {
+            len: usize::MAX,
+            max: 16
+        })
+    );
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        for n in 1..=16 / len {
+            assert_eq!(s.repeat(n).unwrap(), text[..len].repeat(n).as_str());
+        }
+        assert!(s.repeat(16 / len + 1).is_err());
+    }
+}
+
+#[test]
+fn tiny16_widen() {
+    let s4: TinyStr4 = "ab".parse().unwrap();
+    let s8: TinyStr8 = "abc".parse().unwrap();
+    assert_eq!(TinyStr8::from(s4), "ab");
+    assert_eq!(TinyStr16::from(s4), "ab");
+    assert_eq!(TinyStr16::from(s8), "abc");
+    assert_eq!(TinyStr8::from(s4).repeat(4).unwrap(), "abababab");
+    assert_eq!(TinyStr16::from(s4).repeat(8).unwrap(), "abababababababab");
+    assert_eq!(TinyStr16::from(s8).repeat(5).unwrap(), "abcabcabcabcabc");
+    assert_eq!(
+        TinyStr16::from(s8).repeat(6),
+        Err(Error::InvalidSize { len: 18, max: 16 })
+    );
+    let full: TinyStr4 = "abcd".parse().unwrap();
+    assert_eq!(TinyStr8::from(full), "abcd");
+    assert_eq!(TinyStr8::from(full).repeat(2).unwrap(), "abcdabcd");
+    assert!(TinyStr8::from(full).repeat(3).is_err());
+}
+
+#[test]
+fn tiny16_first_last_match_chars() {
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert_eq!(Some(s.first()), s.as_str().chars().next());
+        assert_eq!(Some(s.last()), s.as_str().chars().last());
+        assert_eq!(s.first() == s.last(), len == 1);
+    }
+}
+
+#[test]
+fn tiny16_concat() {
+    let text = "abcdefghijklmnop";
+    for len in 1..16 {
+        let a: TinyStr16 = text[..len].parse().unwrap();
+        let b: TinyStr16 = text[len..16].parse().unwrap();
+        let s = a.try_concat(b).unwrap();
+        assert_eq!(s, &text[..16]);
+        assert_eq!(s.len(), 16);
+        assert_eq!(&*s, &text[..16]);
+        assert_eq!(a + b, s);
+        let parsed: TinyStr16 = text[..16].parse().unwrap();
+        assert_eq!(s.cmp(&parsed), Ordering::Equal);
+        assert_eq!(s.cmp(&a), Ordering::Greater);
+    }
+    let en: TinyStr16 = "en".parse().unwrap();
+    let dash: TinyStr16 = "-".parse().unwrap();
+    assert_eq!((en + dash).try_concat(dash).unwrap(), "en--");
+    let full: TinyStr16 = text[..16].parse().unwrap();
+    assert_eq!(
+        full.try_concat(dash),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny16_add_overflow() {
+    let full: TinyStr16 = "abcdefghijklmnop"[..16].parse().unwrap();
+    let _ = full + full;
+}
+
+#[test]
+fn tiny16_display_format() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    assert_eq!(format!("{:>8}", s), "      en");
+    assert_eq!(format!("{:<6}|", s), "en    |");
+    assert_eq!(format!("{:*^6}", s), "**en**");
+    assert_eq!(format!("{:.1}", s), "e");
+    assert_eq!(format!("{:>4.1}", s), "   e");
+    let buf = TinyStrBuf16::from(s);
+    assert_eq!(format!("{:-<5}", buf), "en---");
+}
+
+#[test]
+fn tiny16_strip_char() {
+    let s: TinyStr16 = "-zh-Hant-TW-".parse().unwrap();
+    assert_eq!(s.strip_prefix_char('-'), Some("zh-Hant-TW-"));
+    assert_eq!(s.strip_suffix_char('-'), Some("-zh-Hant-TW"));
+    assert_eq!(s.strip_prefix_char('e'), None);
+    assert_eq!(s.strip_suffix_char('n'), None);
+    assert_eq!(s.strip_prefix_char('é'), None);
+    assert_eq!(s.strip_prefix_str("-"), Some("zh-Hant-TW-"));
+    assert_eq!(s.strip_prefix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("en"), None);
+    let x: TinyStr16 = "x".parse().unwrap();
+    assert_eq!(x.strip_prefix_char('x'), Some(""));
+    assert_eq!(x.strip_suffix_char('x'), Some(""));
+    assert_eq!(x.strip_prefix_char('y'), None);
+}
+
+#[test]
+fn tiny16_as_raw_hex() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    assert_eq!(s.as_raw_hex(), "0x00000000000000000000000000006e65");
+    assert_eq!(s.as_raw_hex().len(), 2 + 2 * 16);
+    assert_eq!(format!("{:#?}", s), "\"en\"");
+}
+
+#[test]
+fn tiny16_try_push() {
+    let text = "abcdefghijklmnop";
+    let mut s: TinyStr16 = "a".parse().unwrap();
+    for len in 2..=16 {
+        s = s.try_push(text.as_bytes()[len - 1] as char).unwrap();
+        assert_eq!(s, &text[..len]);
+    }
+    assert_eq!(
+        s.try_push('x'),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    let s: TinyStr16 = "ab".parse().unwrap();
+    assert_eq!(s.try_push('é'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\u{4000}'), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(s.try_push('\0'), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny16_try_push_str() {
+    let text = "abcdefghijklmnopq";
+    let s: TinyStr16 = "a".parse().unwrap();
+    assert_eq!(s.try_push_str(&text[1..16]).unwrap(), &text[..16]);
+    assert_eq!(s.try_push_str("").unwrap(), s);
+    assert_eq!(
+        s.try_push_str(&text[1..=16]),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        s.try_push_str(text),
+        Err(Error::InvalidSize { len: 18, max: 16 })
+    );
+    let full: TinyStr16 = text[..16].parse().unwrap();
+    assert_eq!(
+        full.try_push_str("x"),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(s.try_push_str("é"), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(s.try_push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+}
+
+#[test]
+fn tiny16_byte_layout() {
+    // These hold on every target; CI also runs them on big-endian s390x.
+    let fr: TinyStr16 = "fr".parse().unwrap();
+    let zh: TinyStr16 = "zh".parse().unwrap();
+    assert!(fr < zh);
+    assert!("a".parse::<TinyStr16>().unwrap() < "aa".parse::<TinyStr16>().unwrap());
+    assert!("aa".parse::<TinyStr16>().unwrap() < "b".parse::<TinyStr16>().unwrap());
+    assert_eq!(fr.to_bytes()[..3], *b"fr\0");
+    assert_eq!(u128::from(fr), 0x7266);
+    assert_eq!(unsafe { TinyStr16::new_unchecked(0x7266) }, "fr");
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        assert_eq!(s.deref().len(), len);
+        assert_eq!(s.to_string(), &text[..len]);
+        assert_eq!(
+            s.to_ascii_uppercase(),
+            text[..len].to_ascii_uppercase().as_str()
+        );
+        assert_eq!(s.last(), text.as_bytes()[len - 1] as char);
+    }
+}
+
+#[test]
+fn tiny16_buf_write() {
+    let (prefix, idx, long, e_acute) = ("x", 7, "abcdefghijklmnop", 'é');
+    let mut buf = TinyStrBuf16::new();
+    assert_eq!(buf.remaining_capacity(), 16);
+    write!(buf, "{}{:02}", prefix, idx).unwrap();
+    assert_eq!(buf.as_str(), "x07");
+    assert_eq!(buf.remaining_capacity(), 13);
+    buf.push_str("abcdefghijklm").unwrap();
+    assert_eq!(buf.remaining_capacity(), 0);
+    assert_eq!(buf.build().unwrap(), "x07abcdefghijklm");
+    assert!(write!(buf, "y").is_err());
+
+    let mut buf = TinyStrBuf16::new();
+    assert!(write!(buf, "ab-{}", long).is_err());
+    assert_eq!(buf.as_str(), "ab-");
+    let mut buf = TinyStrBuf16::new();
+    assert!(write!(buf, "a{}", e_acute).is_err());
+    assert_eq!(buf.as_str(), "a");
+    assert_eq!(buf.push_str("bé"), Err(Error::NonAscii { index: 2 }));
+    assert_eq!(buf.push_str("b\0"), Err(Error::InvalidNull { index: 2 }));
+    assert_eq!(
+        buf.push_str("abcdefghijklmnop"),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(buf.as_str(), "a");
+}
+
+#[test]
+fn tiny16_builder() {
+    struct Accented;
+    impl fmt::Display for Accented {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("é")
+        }
+    }
+
+    let prefix = "fixture";
+    let mut builder = TinyStr16Builder::new();
+    write!(builder, "{}-{:08}", prefix, 42).unwrap();
+    assert_eq!(builder.remaining_capacity(), 0);
+    assert_eq!(
+        builder.finish(),
+        Ok("fixture-00000042".parse::<TinyStr16>().unwrap())
+    );
+
+    let mut builder = TinyStr16Builder::new();
+    assert!(write!(builder, "{}-{:08}", prefix, 420000000).is_err());
+    assert_eq!(builder.as_str(), "fixture-");
+
+    let mut builder = TinyStr16Builder::new();
+    builder.push('a').unwrap();
+    assert!(write!(builder, "{}", Accented).is_err());
+    assert_eq!(builder.finish(), Ok("a".parse::<TinyStr16>().unwrap()));
+
+    assert_eq!(
+        TinyStr16Builder::new().finish(),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+}
+
+#[test]
+fn tiny16_count_char() {
+    let text = "abcdefghijklmnop";
+    let s: TinyStr16 = text[..16].parse().unwrap();
+    for c in text[..16].chars() {
+        assert_eq!(s.count_char(c), 1);
+    }
+    assert_eq!(s.count_char('z'), 0);
+    assert_eq!(s.count_char('é'), 0);
+    assert_eq!(s.count_char('\0'), 0);
+    let s: TinyStr16 = "a-aa-aaa-aaaa-a".parse().unwrap();
+    assert_eq!(s.count_char('a'), s.as_str().matches('a').count());
+    assert_eq!(s.count_char('-'), s.as_str().matches('-').count());
+    let s: TinyStr16 = "x".repeat(16).parse().unwrap();
+    assert_eq!(s.count_char('x'), 16);
+}
+
+#[test]
+fn tiny16_parse_rejects_every_position() {
+    for len in 1..=16 {
+        for pos in 0..len {
+            let mut bytes = vec![b'a'; len];
+            bytes[pos] = 0;
+            let text = String::from_utf8(bytes).unwrap();
+            assert_eq!(
+                text.parse::<TinyStr16>(),
+                Err(Error::InvalidNull { index: pos })
+            );
+            if pos + 1 < len {
+                let text = format!("{}é{}", "a".repeat(pos), "a".repeat(len - pos - 2));
+                assert_eq!(
+                    text.parse::<TinyStr16>(),
+                    Err(Error::NonAscii { index: pos })
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tiny16_from_display() {
+    struct Repeat(usize);
+
+    impl fmt::Display for Repeat {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for _ in 0..self.0 {
+                f.write_str("ab")?;
+            }
+            Ok(())
+        }
+    }
+
+    assert_eq!(TinyStr16::from_display(&42).unwrap(), "42");
+    assert_eq!(
+        TinyStr16::from_display(&Repeat(8)).unwrap(),
+        "ab".repeat(8).as_str()
+    );
+    assert_eq!(
+        TinyStr16::from_display(&Repeat(9)),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::from_display(&'é'),
+        Err(Error::NonAscii { index: 0 })
+    );
+    assert_eq!(
+        TinyStr16::from_display(&""),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+
+    let idx = 7;
+    assert_eq!(format_tiny!(TinyStr16, "x{:03}", idx).unwrap(), "x007");
+    assert_eq!(
+        format_tiny!(TinyStr16, "{:>16}", idx).unwrap(),
+        format!("{:>16}", idx).as_str()
+    );
+    assert_eq!(
+        format_tiny!(TinyStr16, "{:>17}", idx),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        format_tiny!(TinyStr16, "a{}", "\0"),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny16_is_palindrome() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    assert_eq!(s.reverse(), "ne");
+    assert!(!s.is_palindrome());
+    assert!("x".parse::<TinyStr16>().unwrap().is_palindrome());
+    assert!("aa".parse::<TinyStr16>().unwrap().is_palindrome());
+    assert!("aba".parse::<TinyStr16>().unwrap().is_palindrome());
+    assert!("abcdefghgfedcba"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_palindrome());
+    assert!(!"abcdefghgfedcbaa"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_palindrome());
+}
+
+#[test]
+fn tiny16_trim() {
+    let s: TinyStr16 = " en".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en");
+    assert_eq!(s.trim_end().unwrap(), " en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr16 = "en\t".parse().unwrap();
+    assert_eq!(s.trim_start().unwrap(), "en\t");
+    assert_eq!(s.trim_end().unwrap(), "en");
+    assert_eq!(s.trim().unwrap(), "en");
+
+    let s: TinyStr16 = "\n\t a b c\r \n".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), "a b c");
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    let s: TinyStr16 = "\x0b\x0cx".parse().unwrap();
+    assert_eq!(s.trim().unwrap(), s.as_str().trim());
+
+    assert_eq!(
+        "                ".parse::<TinyStr16>().unwrap().trim(),
+        None
+    );
+    assert_eq!(" ".parse::<TinyStr16>().unwrap().trim_start(), None);
+    assert_eq!(" ".parse::<TinyStr16>().unwrap().trim_end(), None);
+    assert_eq!(
+        "\x08a\x0e".parse::<TinyStr16>().unwrap().trim().unwrap(),
+        "\x08a\x0e"
+    );
+}
+
+#[test]
+fn tiny16_split_once_str() {
+    let s: TinyStr16 = "-en".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("", "en")));
+    let s: TinyStr16 = "en-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("en", "")));
+    let s: TinyStr16 = "zh-Hant-TW".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("zh", "Hant-TW")));
+    assert_eq!(s.split_once_str('_'), None);
+    assert_eq!(s.split_once_str('é'), None);
+    assert_eq!(s.split_once_str('\0'), None);
+    let s: TinyStr16 = "a-b-".parse().unwrap();
+    assert_eq!(s.split_once_str('-'), Some(("a", "b-")));
+    assert_eq!(s.split_once_str('-'), s.as_str().split_once('-'));
+}
+
+#[test]
+fn tiny16_parse_many() {
+    let mut inputs = vec![
+        "",
+        "a",
+        "en",
+        "MacintoshOSX2019",
+        "MacintoshOSX20190",
+        "\0",
+        "a\0",
+        "\0a",
+        "é",
+        "aé\0",
+        "a\0é",
+        "\u{7f}\u{1}",
+    ];
+    let texts: Vec<String> = (1..=16)
+        .flat_map(|len| {
+            (0..len).flat_map(move |i| {
+                ["\0", "\u{80}", "\u{7f}"].iter().map(move |bad| {
+                    let mut s = "x".repeat(i);
+                    s.push_str(bad);
+                    s.push_str(&"y".repeat(len - i - 1));
+                    s
+                })
+            })
+        })
+        .collect();
+    inputs.extend(texts.iter().map(String::as_str));
+
+    let mut out = vec![Err(Error::InvalidSize { len: 0, max: 0 })];
+    TinyStr16::parse_many(&inputs, &mut out);
+    assert_eq!(out.len(), inputs.len() + 1);
+    for (text, result) in inputs.iter().zip(&out[1..]) {
+        assert_eq!(result, &text.parse::<TinyStr16>(), "{:?}", text);
+    }
+}
+
+#[test]
+fn tiny16_eq_ignore_ascii_case() {
+    let s: TinyStr16 = "Zh@[`~Hant-TW".parse().unwrap();
+    let t: TinyStr16 = "zH@[`~hANT-tw".parse().unwrap();
+    assert!(s.eq_ignore_ascii_case(&t));
+    assert!(s.eq_ignore_ascii_case_str(t.as_str()));
+    assert!(!s.eq_ignore_ascii_case(&"zh".parse().unwrap()));
+    assert!(!s.eq_ignore_ascii_case_str("Zh@[`~Hant-TWé"));
+    assert!(!s.eq_ignore_ascii_case_str(""));
+
+    for a in 1..=0x7fu8 {
+        for b in 1..=0x7fu8 {
+            let bytes_a = [b'x', a];
+            let bytes_b = [b'X', b];
+            let sa = std::str::from_utf8(&bytes_a).unwrap();
+            let sb = std::str::from_utf8(&bytes_b).unwrap();
+            let ta: TinyStr16 = sa.parse().unwrap();
+            let tb: TinyStr16 = sb.parse().unwrap();
+            assert_eq!(ta.eq_ignore_ascii_case(&tb), sa.eq_ignore_ascii_case(sb));
+        }
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny16_eq_ignore_ascii_case_matches_str(
+        a in "[\x01-\x7f]{1,16}",
+        b in "[\x01-\x7f]{1,16}",
+        flips in proptest::prelude::any::<u16>(),
+    ) {
+        let folded: String = a
+            .chars()
+            .enumerate()
+            .map(|(i, c)| if flips >> i & 1 == 1 { c.to_ascii_uppercase() } else { c })
+            .collect();
+        let ta: TinyStr16 = a.parse().unwrap();
+        let tb: TinyStr16 = b.parse().unwrap();
+        let tf: TinyStr16 = folded.parse().unwrap();
+        assert_eq!(ta.eq_ignore_ascii_case(&tb), a.eq_ignore_ascii_case(&b));
+        assert!(ta.eq_ignore_ascii_case(&tf));
+        assert!(ta.eq_ignore_ascii_case_str(&folded));
+    }
+}
+
+#[test]
+fn tiny16_fixed_bytes() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    assert_eq!(s.to_fixed_bytes::<16>(), s.to_bytes());
+    assert_eq!(s.to_fixed_bytes::<20>()[..4], [0x65, 0x6e, 0, 0]);
+    assert_eq!(&s.to_fixed_bytes::<20>()[..3], &[0x65, 0x6e, 0]);
+    assert_eq!(TinyStr16::from_fixed_bytes(s.to_fixed_bytes::<20>()), Ok(s));
+
+    let text = "abcdefghijklmnop";
+    for len in 1..=16 {
+        let s: TinyStr16 = text[..len].parse().unwrap();
+        let bytes: [u8; 16] = s.to_fixed_bytes();
+        assert_eq!(&bytes[..len], &text.as_bytes()[..len]);
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        assert_eq!(TinyStr16::from_fixed_bytes(bytes), Ok(s));
+    }
+
+    assert_eq!(
+        TinyStr16::from_fixed_bytes([b'a', 0, b'b', 0]),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_fixed_bytes([b'a', 0xe9, 0, 0]),
+        Err(Error::NonAscii { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_fixed_bytes([0; 4]),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+    assert_eq!(
+        TinyStr16::from_fixed_bytes([b'a'; 17]),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn tiny16_fixed_bytes_too_short() {
+    let s: TinyStr16 = "en".parse().unwrap();
+    let _: [u8; 15] = s.to_fixed_bytes();
+}
+
+#[test]
+fn tiny16_cmp_ignore_ascii_case() {
+    let parse = |s: &str| s.parse::<TinyStr16>().unwrap();
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("cs")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("fr")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("DE").cmp_ignore_ascii_case(&parse("de")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("de").cmp_ignore_ascii_case(&parse("DE")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        parse("De").cmp_ignore_ascii_case(&parse("De")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        parse("a_").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+    assert_eq!(
+        parse("a").cmp_ignore_ascii_case(&parse("AZ")),
+        Ordering::Less
+    );
+
+    let mut v: Vec<TinyStr16> = [
+        "fr", "DE", "cs", "de", "De", "EN", "en", "a_", "A", "[", "`", "zz",
+    ]
+    .iter()
+    .map(|s| parse(s))
+    .collect();
+    let mut expected: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    expected.sort_by(|a, b| {
+        a.to_ascii_lowercase()
+            .cmp(&b.to_ascii_lowercase())
+            .then_with(|| a.cmp(b))
+    });
+    v.sort_by(|a, b| a.cmp_ignore_ascii_case(b));
+    let v: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn tiny16_halves() {
+    let lo: TinyStr8 = "en-Latn-".parse().unwrap();
+    let hi: TinyStr8 = "US".parse().unwrap();
+    let s = TinyStr16::from_halves(lo, hi).unwrap();
+    assert_eq!(s, "en-Latn-US");
+    assert_eq!(s.into_halves(), (lo, Some(hi)));
+
+    let full: TinyStr16 = "abcdefghijklmnop".parse().unwrap();
+    let (lo, hi) = full.into_halves();
+    assert_eq!(lo, "abcdefgh");
+    assert_eq!(hi.unwrap(), "ijklmnop");
+    assert_eq!(TinyStr16::from_halves(lo, hi.unwrap()), Ok(full));
+
+    let s: TinyStr16 = "abcdefgh".parse().unwrap();
+    assert_eq!(s.into_halves(), (s.as_str().parse().unwrap(), None));
+    let s: TinyStr16 = "abcdefghi".parse().unwrap();
+    assert_eq!(s.into_halves().1.unwrap(), "i");
+
+    let short: TinyStr8 = "en-US".parse().unwrap();
+    assert_eq!(
+        TinyStr16::from_halves(short, short),
+        Err(Error::InvalidNull { index: 5 })
+    );
+}
+
+#[test]
+fn tiny16_case_insensitive() {
+    let parse = |s: &str| CaseInsensitive::from(s.parse::<TinyStr16>().unwrap());
+    let upper = parse("GZIP");
+    let lower = parse("gzip");
+    assert_eq!(upper, lower);
+    assert_eq!(upper.cmp(&lower), Ordering::Equal);
+    assert_ne!(upper, parse("gzi"));
+    assert!(parse("DE") > parse("cs"));
+    assert!(parse("DE") < parse("fr"));
+    assert_eq!(upper.to_string(), "GZIP");
+    assert_eq!(format!("{:>5}", lower), " gzip");
+    assert_eq!(*upper, "GZIP");
+    assert_eq!(upper.len(), 4);
+    assert_eq!(upper.into_inner(), "GZIP");
+
+    let hash = |s: &CaseInsensitive<TinyStr16>| {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&upper), hash(&lower));
+
+    let mut map = HashMap::new();
+    map.insert(upper, 1);
+    map.insert(lower, 2);
+    map.insert(parse("Gzip"), 3);
+    map.insert(parse("br"), 4);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&parse("gZiP")], 3);
+
+    let mut tree = BTreeMap::new();
+    tree.insert(upper, 1);
+    tree.insert(lower, 2);
+    tree.insert(parse("BR"), 3);
+    tree.insert(parse("br"), 4);
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree[&parse("gzip")], 2);
+    let keys: Vec<String> = tree.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["BR", "GZIP"]);
+}
+
+#[test]
+fn tiny16_is_ascii_numeric_boundaries() {
+    assert!("9999999999999999"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_numeric());
+    assert!(!"1/".parse::<TinyStr16>().unwrap().is_ascii_numeric());
+    assert!(!":1".parse::<TinyStr16>().unwrap().is_ascii_numeric());
+    assert!(!"419abcdefghijk1"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_numeric());
+    for b in 1..=0x7fu8 {
+        for i in 0..16 {
+            let mut bytes = [b'5'; 16];
+            bytes[i] = b;
+            let text = std::str::from_utf8(&bytes[..=i]).unwrap();
+            let s: TinyStr16 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_numeric(),
+                text.chars().all(|c| c.is_ascii_digit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny16_count_classes() {
+    let s: TinyStr16 = "Hant-419_xYZ@[`".parse().unwrap();
+    assert_eq!(s.count_uppercase(), 3);
+    assert_eq!(s.count_lowercase(), 4);
+    assert_eq!(s.count_digits(), 3);
+    assert_eq!(s.count_alphabetic(), 7);
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=16 {
+            let mut bytes = [b'0'; 16];
+            bytes[len - 1] = b;
+            bytes[0] = b'a';
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr16 = text.parse().unwrap();
+            let count = |f: fn(&char) -> bool| text.chars().filter(f).count();
+            assert_eq!(s.count_uppercase(), count(char::is_ascii_uppercase));
+            assert_eq!(s.count_lowercase(), count(char::is_ascii_lowercase));
+            assert_eq!(s.count_digits(), count(char::is_ascii_digit));
+            assert_eq!(s.count_alphabetic(), count(char::is_ascii_alphabetic));
+        }
+    }
+}
+
+#[test]
+fn tiny16_is_ascii_case_matches_std() {
+    let s: TinyStr16 = "419".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+    let s: TinyStr16 = "zh-hant_419@[`~".parse().unwrap();
+    assert!(s.is_ascii_lowercase());
+    assert!(!s.is_ascii_uppercase());
+    let s: TinyStr16 = "ZH-HANT_419@[`~".parse().unwrap();
+    assert!(!s.is_ascii_lowercase());
+    assert!(s.is_ascii_uppercase());
+
+    for b in 1..=0x7fu8 {
+        for len in 1..=16 {
+            let mut bytes = [b'1'; 16];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr16 = text.parse().unwrap();
+            assert_eq!(s.is_ascii_lowercase(), text.to_ascii_lowercase() == text);
+            assert_eq!(s.is_ascii_uppercase(), text.to_ascii_uppercase() == text);
+        }
+    }
+}
+
+#[test]
+fn tiny16_from_str_validating() {
+    let text = "en-1";
+    assert_eq!(
+        TinyStr16::from_str_validating(text, |b| b.is_ascii_alphanumeric()),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_validating("en1", |b| b.is_ascii_alphanumeric()),
+        Ok("en1".parse().unwrap())
+    );
+    let letter_or_hyphen = |b: u8| b.is_ascii_alphabetic() || b == b'-';
+    assert_eq!(
+        TinyStr16::from_str_validating("zh-Hant-TW", letter_or_hyphen),
+        Ok("zh-Hant-TW".parse().unwrap())
+    );
+    assert_eq!(
+        TinyStr16::from_str_validating("a-1", letter_or_hyphen),
+        Err(Error::FailedValidation { index: 2 })
+    );
+    assert_eq!(TinyStr16::from_str_validating(text, |_| true), text.parse());
+    assert_eq!(
+        TinyStr16::from_str_validating("419", |b| !b.is_ascii_digit()),
+        Err(Error::FailedValidation { index: 0 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_validating("a\0", |_| false),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::from_str_validating("", |_| true),
+        Err(Error::InvalidSize { len: 0, max: 16 })
+    );
+}
+
+#[test]
+fn tiny16_is_ascii_hexdigit_boundaries() {
+    assert!("0123456789abcdef"
+        .parse::<TinyStr16>()
+        .unwrap()
+        .is_ascii_hexdigit());
+    for bad in ["g", "G", "`", "@", ":", "/"].iter() {
+        for len in 1..=16 {
+            for i in 0..len {
+                let mut text = "F".repeat(len);
+                text.replace_range(i..=i, bad);
+                let s: TinyStr16 = text.parse().unwrap();
+                assert!(!s.is_ascii_hexdigit(), "{:?}", text);
+            }
+        }
+    }
+    for b in 1..=0x7fu8 {
+        for len in 1..=16 {
+            let mut bytes = [b'a'; 16];
+            bytes[len - 1] = b;
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let s: TinyStr16 = text.parse().unwrap();
+            assert_eq!(
+                s.is_ascii_hexdigit(),
+                text.chars().all(|c| c.is_ascii_hexdigit()),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny16_swapcase_boundaries() {
+    assert_eq!(
+        "aB1".parse::<TinyStr16>().unwrap().to_ascii_swapcase(),
+        "Ab1"
+    );
+    assert_eq!(
+        "@[`~".parse::<TinyStr16>().unwrap().to_ascii_swapcase(),
+        "@[`~"
+    );
+    assert_eq!(
+        "{AZ}".parse::<TinyStr16>().unwrap().to_ascii_swapcase(),
+        "{az}"
+    );
+    assert_eq!(
+        "@az[".parse::<TinyStr16>().unwrap().to_ascii_swapcase(),
+        "@AZ["
+    );
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s: TinyStr16 = c.to_string().parse().unwrap();
+        let expected = if c.is_ascii_uppercase() {
+            c.to_ascii_lowercase()
+        } else {
+            c.to_ascii_uppercase()
+        };
+        assert_eq!(s.to_ascii_swapcase(), expected.to_string().as_str());
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn tiny16_swapcase_matches_chars(text in "[\x01-\x7f]{1,16}") {
+        let s: TinyStr16 = text.parse().unwrap();
+        let expected: String = text
+            .chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect();
+        assert_eq!(s.to_ascii_swapcase().as_str(), expected);
+    }
+}
+
+#[test]
+fn tiny16_identifiers() {
+    let parse = |s: &str| s.parse::<TinyStr16>().unwrap();
+    for (text, word, start) in [
+        ("_foo", true, true),
+        ("foo1", true, true),
+        ("1foo", true, false),
+        ("fo-o", false, false),
+        ("_", true, true),
+        ("9", true, false),
+        ("a b", false, false),
+        ("Z_9zA_9zZ_9zA_9z", true, true),
+    ]
+    .iter()
+    {
+        let s = parse(text);
+        assert_eq!(s.is_ascii_word_char(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_continue(), *word, "{:?}", text);
+        assert_eq!(s.is_ascii_identifier_start(), *start, "{:?}", text);
+    }
+    for b in 1..=0x7fu8 {
+        let c = b as char;
+        let s = parse(&format!("a{}", c));
+        assert_eq!(
+            s.is_ascii_word_char(),
+            c.is_ascii_alphanumeric() || c == '_',
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn tiny16_to_u64() {
+    assert_eq!("419".parse::<TinyStr16>().unwrap().to_u64(), Some(419));
+    assert_eq!("007".parse::<TinyStr16>().unwrap().to_u64(), Some(7));
+    assert_eq!("0".parse::<TinyStr16>().unwrap().to_u64(), Some(0));
+    assert_eq!("4a9".parse::<TinyStr16>().unwrap().to_u64(), None);
+    assert_eq!("-1".parse::<TinyStr16>().unwrap().to_u64(), None);
+    assert_eq!(" 1".parse::<TinyStr16>().unwrap().to_u64(), None);
+    assert_eq!(
+        "9999999999999999".parse::<TinyStr16>().unwrap().to_u64(),
+        Some(9_999_999_999_999_999)
+    );
+}
+
+#[test]
+fn tiny16_titlecase_segments() {
+    fn reference(text: &str, sep: char) -> String {
+        let mut start = true;
+        text.chars()
+            .map(|c| {
+                let out = if start {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                };
+                start = c == sep;
+                out
+            })
+            .collect()
+    }
+
+    let s: TinyStr16 = "zh-hant-tw".parse().unwrap();
+    assert_eq!(s.to_ascii_titlecase_segments('-'), "Zh-Hant-Tw");
+    assert_eq!(s.to_ascii_titlecase_segments('_'), "Zh-hant-tw");
+    assert_eq!(s.to_ascii_titlecase_segments('é'), s.to_ascii_titlecase());
+    assert_eq!(s.to_ascii_titlecase_segments('\0'), s.to_ascii_titlecase());
+
+    for text in ["-ab", "ab-", "a--b", "-", "1a-b", "A-1B", "AB__cd-EF_1g_hI"].iter() {
+        let s: TinyStr16 = text.parse().unwrap();
+        for sep in ['-', '_'].iter() {
+            assert_eq!(
+                s.to_ascii_titlecase_segments(*sep),
+                reference(text, *sep).as_str(),
+                "{:?} {:?}",
+                text,
+                sep
+            );
+        }
+    }
+}
+
+#[test]
+fn tiny16_upper_lower_first() {
+    let parse = |s: &str| s.parse::<TinyStr16>().unwrap();
+    assert_eq!(parse("latn").to_upper_first(), "Latn");
+    assert_eq!(parse("Latn").to_upper_first(), "Latn");
+    assert_eq!(parse("lATN").to_upper_first(), "LATN");
+    assert_eq!(parse("1atn").to_upper_first(), "1atn");
+    assert_eq!(parse("@atn").to_upper_first(), "@atn");
+    assert_eq!(parse("`atn").to_upper_first(), "`atn");
+    assert_eq!(parse("z").to_upper_first(), "Z");
+
+    assert_eq!(parse("LATN").to_lower_first(), "lATN");
+    assert_eq!(parse("lATN").to_lower_first(), "lATN");
+    assert_eq!(parse("[ATN").to_lower_first(), "[ATN");
+    assert_eq!(parse("A").to_lower_first(), "a");
+
+    let s = parse("xYz9-aB_Cd~eF@[");
+    assert_eq!(s.to_upper_first()[1..], s[1..]);
+    assert_eq!(s.to_lower_first(), s);
+}
+
+#[test]
+fn tiny16_strip_prefix_str_private_use() {
+    let s: TinyStr16 = "x-abcdefghijklm".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), Some("abcdefghijklm"));
+    assert_eq!(s.strip_prefix_str("X-"), None);
+    assert_eq!(s.strip_prefix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_prefix_str("x-abcdefghijklmn"), None);
+    let s: TinyStr16 = "en-x".parse().unwrap();
+    assert_eq!(s.strip_prefix_str("x-"), None);
+}
+
+#[test]
+fn tiny16_rotate() {
+    let s: TinyStr16 = "abcdefghijklmnop".parse().unwrap();
+    assert_eq!(s.rotate_left(0), s);
+    assert_eq!(s.rotate_right(0), s);
+    assert_eq!(s.rotate_left(s.len()), s);
+    assert_eq!(s.rotate_right(s.len()), s);
+    assert_eq!(s.rotate_left(1), "bcdefghijklmnopa");
+    assert_eq!(s.rotate_right(1), "pabcdefghijklmno");
+    assert_eq!(s.rotate_left(s.len() + 1), s.rotate_left(1));
+
+    for text in &["a", "ab", "abc", "abcdefghijk"] {
+        let s: TinyStr16 = text.parse().unwrap();
+        let mut expected = text.as_bytes().to_vec();
+        expected.rotate_left(1);
+        assert_eq!(s.rotate_left(1).as_bytes(), &expected[..]);
+        for n in 0..=2 * s.len() {
+            assert_eq!(s.rotate_left(n).rotate_right(n), s);
+            assert_eq!(s.rotate_right(n).rotate_left(n), s);
+        }
+    }
+}
+
+#[test]
+fn tiny16_strip_suffix_str() {
+    let s: TinyStr16 = "en-x-private-use".parse().unwrap();
+    assert_eq!(s.strip_suffix_str("-use"), Some("en-x-private"));
+    assert_eq!(s.strip_suffix_str(""), Some(s.as_str()));
+    assert_eq!(s.strip_suffix_str(s.as_str()), Some(""));
+    assert_eq!(s.strip_suffix_str("-USE"), None);
+    assert_eq!(s.strip_suffix_str("en"), None);
+    assert_eq!(s.strip_suffix_str("ien-x-private-use"), None);
+    let x: TinyStr16 = "x".parse().unwrap();
+    assert_eq!(x.strip_suffix_str("x"), Some(""));
+    assert_eq!(x.strip_suffix_str("xx"), None);
+}
+
+#[test]
+fn tiny16_with_prefix_suffix() {
+    let s: TinyStr16 = "abcdefghijklmno".parse().unwrap();
+    let full = s.with_suffix('z').unwrap();
+    assert_eq!(full, "abcdefghijklmnoz");
+    assert_eq!(s.with_prefix('z').unwrap(), "zabcdefghijklmno");
+    assert_eq!(
+        full.with_suffix('z'),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    assert_eq!(
+        full.with_prefix('z'),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+
+    let a: TinyStr16 = "a".parse().unwrap();
+    assert_eq!(a.with_prefix('-').unwrap(), "-a");
+    assert_eq!(a.with_suffix('-').unwrap(), "a-");
+    assert_eq!(a.with_prefix('é'), Err(Error::NonAscii { index: 0 }));
+    assert_eq!(a.with_suffix('é'), Err(Error::NonAscii { index: 1 }));
+    assert_eq!(a.with_prefix('\0'), Err(Error::InvalidNull { index: 0 }));
+    assert_eq!(a.with_suffix('\0'), Err(Error::InvalidNull { index: 1 }));
+}
+
+#[test]
+fn tiny16_to_u64_radix() {
+    let parse = |s: &str| s.parse::<TinyStr16>().unwrap();
+    assert_eq!(parse("ff").to_u64_radix(16), Some(255));
+    assert_eq!(parse("FF").to_u64_radix(16), Some(255));
+    assert_eq!(parse("101").to_u64_radix(2), Some(5));
+    assert_eq!(parse("102").to_u64_radix(2), None);
+    assert_eq!(parse("zz").to_u64_radix(36), Some(36 * 36 - 1));
+    assert_eq!(parse("-1").to_u64_radix(10), None);
+    assert_eq!(parse("+1").to_u64_radix(10), None);
+    assert_eq!(parse("ffffffffffffffff").to_u64_radix(16), Some(u64::MAX));
+    assert_eq!(parse("zzzzzzzzzzzzzzzz").to_u64_radix(36), None);
+
+    for text in &["0", "7", "42", "419", "9999999999999999", "ab", "x1"] {
+        for radix in &[2, 8, 10, 16, 36] {
+            assert_eq!(
+                parse(text).to_u64_radix(*radix),
+                u64::from_str_radix(text, *radix).ok(),
+                "{} in radix {}",
+                text,
+                radix
+            );
+        }
+        assert_eq!(parse(text).to_u64_radix(10), parse(text).to_u64());
+    }
+}
+
+#[test]
+#[should_panic]
+fn tiny16_to_u64_radix_bad_radix() {
+    let _ = "1".parse::<TinyStr16>().unwrap().to_u64_radix(37);
+}
+
+#[test]
+fn tiny16_common_prefix_len() {
+    let parse = |s: &str| s.parse::<TinyStr16>().unwrap();
+    assert_eq!(parse("enx").common_prefix_len(&parse("eny")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("abc")), 3);
+    assert_eq!(parse("ab").common_prefix_len(&parse("xy")), 0);
+    assert_eq!(parse("ab").common_prefix_len(&parse("abc")), 2);
+    assert_eq!(parse("abc").common_prefix_len(&parse("ab")), 2);
+    assert_eq!(parse("ab").common_prefix_len(&parse("aB")), 1);
+
+    let full = parse("abcdefghijklmnop");
+    assert_eq!(full.common_prefix_len(&full), 16);
+    for text in &["a", "ab", "abx", "abcdefghijklmnox", "b"] {
+        let s = parse(text);
+        let expected = full
+            .bytes()
+            .zip(s.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        assert_eq!(full.common_prefix_len(&s), expected, "{}", text);
+        assert_eq!(s.common_prefix_len(&full), expected, "{}", text);
+    }
+}
+
+#[test]
+fn tiny16_char_predicates() {
+    let s: TinyStr16 = "en-US-u-ca-1".parse().unwrap();
+    assert!(s.all_chars(|c| c.is_ascii_graphic()));
+    assert!(!s.all_chars(|c| c.is_ascii_alphabetic()));
+    assert!(s.any_char(|c| c.is_ascii_digit()));
+    assert!(!s.any_char(|c| c == ' '));
+    assert_eq!(
+        s.all_chars(char::is_alphanumeric),
+        s.chars().all(char::is_alphanumeric)
+    );
+
+    assert_eq!(s.map_chars(|c| c), Ok(s));
+    assert_eq!(
+        s.map_chars(|c| c.to_ascii_uppercase()),
+        Ok(s.to_ascii_uppercase())
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { '_' } else { c }).unwrap(),
+        "en_US_u_ca_1"
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == '-' { 'é' } else { c }),
+        Err(Error::NonAscii { index: 2 })
+    );
+    assert_eq!(
+        s.map_chars(|c| if c == 'n' { '\0' } else { c }),
+        Err(Error::InvalidNull { index: 1 })
+    );
+}
+
+#[test]
+fn tiny16_from_decimal() {
+    for n in [
+        0,
+        9,
+        10,
+        99,
+        100,
+        419,
+        999,
+        1000,
+        9999,
+        9_999_999_999_999_999,
+        10_000_000_000_000_000,
+        u64::MAX,
+    ]
+    .iter()
+    {
+        let expected = n.to_string();
+        match TinyStr16::from_decimal(*n) {
+            Ok(s) => {
+                assert_eq!(s, expected.as_str());
+                assert_eq!(s.to_u64(), Some(*n));
+            }
+            Err(err) => {
+                assert!(expected.len() > 16, "{}", n);
+                assert_eq!(
+                    err,
+                    Error::InvalidSize {
+                        len: expected.len(),
+                        max: 16
+                    }
+                );
+            }
+        }
+    }
+
+    assert_eq!(TinyStr16::from_decimal(0).unwrap(), "0");
+    assert_eq!(TinyStr16::from_decimal_padded(7, 3).unwrap(), "007");
+    assert_eq!(TinyStr16::from_decimal_padded(0, 2).unwrap(), "00");
+    assert_eq!(TinyStr16::from_decimal_padded(0, 0).unwrap(), "0");
+    assert_eq!(TinyStr16::from_decimal_padded(419, 2).unwrap(), "419");
+    assert_eq!(TinyStr16::from_decimal_padded(1, 16).unwrap().len(), 16);
+    assert_eq!(
+        TinyStr16::from_decimal_padded(1, 17),
+        Err(Error::InvalidSize { len: 17, max: 16 })
+    );
+    for width in 0..=16 {
+        let s = TinyStr16::from_decimal_padded(42, width).unwrap();
+        assert_eq!(s, format!("{:0width$}", 42, width = width).as_str());
+        assert_eq!(s.to_u64(), Some(42));
+    }
+}
+
+#[test]
+fn cross_size_eq() {
+    let en4: TinyStr4 = "en".parse().unwrap();
+    let en8: TinyStr8 = "en".parse().unwrap();
+    let en16: TinyStr16 = "en".parse().unwrap();
+    let fr4: TinyStr4 = "fr".parse().unwrap();
+    let fr8: TinyStr8 = "fr".parse().unwrap();
+    let fr16: TinyStr16 = "fr".parse().unwrap();
+
+    assert_eq!(en4, en8);
+    assert_eq!(en8, en4);
+    assert_eq!(en4, en16);
+    assert_eq!(en16, en4);
+    assert_eq!(en8, en16);
+    assert_eq!(en16, en8);
+    assert_ne!(en4, fr8);
+    assert_ne!(fr8, en4);
+    assert_ne!(en4, fr16);
+    assert_ne!(fr16, en4);
+    assert_ne!(en8, fr16);
+    assert_ne!(fr16, en8);
+    assert_ne!(fr4, en8);
+    assert_ne!(fr4, en16);
+    assert_ne!(fr8, en16);
+}
+
+#[test]
+fn cross_size_ord() {
+    let strings = ["en", "eng", "en-US", "fr", "Latn", "zh"];
+    for a in strings.iter() {
+        for b in strings.iter() {
+            let expected = a.partial_cmp(b);
+            let (a4, b4) = (a.parse::<TinyStr4>(), b.parse::<TinyStr4>());
+            let (a8, b8): (TinyStr8, TinyStr8) = (a.parse().unwrap(), b.parse().unwrap());
+            let (a16, b16): (TinyStr16, TinyStr16) = (a.parse().unwrap(), b.parse().unwrap());
+            if let Ok(a4) = a4 {
+                assert_eq!(a4.partial_cmp(&b8), expected);
+                assert_eq!(a4.partial_cmp(&b16), expected);
+                assert_eq!(a4 == b8, a == b);
+            }
+            if let Ok(b4) = b4 {
+                assert_eq!(a8.partial_cmp(&b4), expected);
+                assert_eq!(a16.partial_cmp(&b4), expected);
+                assert_eq!(a16 == b4, a == b);
+            }
+            assert_eq!(a8.partial_cmp(&b16), expected);
+            assert_eq!(a16.partial_cmp(&b8), expected);
+            assert_eq!(a8 == b16, a == b);
+        }
+    }
+}
+
+#[test]
+fn parse_many() {
+    let v: Vec<TinyStr4> = tinystr::parse_many(&["en", "fr", "zh"]).unwrap();
+    assert_eq!(v, vec!["en", "fr", "zh"]);
+    let v: Vec<TinyStr8> = tinystr::parse_many(&["windows", "macos"]).unwrap();
+    assert_eq!(v, vec!["windows", "macos"]);
+    let v: Vec<TinyStr16> = tinystr::parse_many(&[]).unwrap();
+    assert!(v.is_empty());
+
+    assert_eq!(
+        tinystr::parse_many::<TinyStr8>(&["\u{4000}", "en", ""]),
+        Err(Error::BatchError {
+            index: 0,
+            inner: Box::new(Error::NonAscii { index: 0 })
+        })
+    );
+    assert_eq!(
+        tinystr::parse_many::<TinyStr16>(&["en", "fr", "a\u{0}b"]),
+        Err(Error::BatchError {
+            index: 2,
+            inner: Box::new(Error::InvalidNull { index: 1 })
+        })
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "nom")]
+
+use nom::error::ErrorKind;
+use nom::Err;
+use tinystr::{
+    parse_tinystr16, parse_tinystr16_exact, parse_tinystr4, parse_tinystr4_exact, parse_tinystr8,
+    parse_tinystr8_exact,
+};
+
+#[test]
+fn tiny4_parse() {
+    let (rest, s) = parse_tinystr4(b"en-US").unwrap();
+    assert_eq!(s, "en-U");
+    assert_eq!(rest, b"S");
+    let (rest, s) = parse_tinystr4(b"en").unwrap();
+    assert_eq!(s, "en");
+    assert_eq!(rest, b"");
+    let (rest, s) = parse_tinystr4(b"en\0fr").unwrap();
+    assert_eq!(s, "en");
+    assert_eq!(rest, b"\0fr");
+    let (rest, s) = parse_tinystr4(b"e\xc3\xa9").unwrap();
+    assert_eq!(s, "e");
+    assert_eq!(rest, b"\xc3\xa9");
+
+    assert!(matches!(parse_tinystr4(b""), Err(Err::Error(_))));
+    assert!(matches!(parse_tinystr4(b"\0en"), Err(Err::Error(_))));
+    assert!(matches!(parse_tinystr4(b"\xffen"), Err(Err::Error(_))));
+}
+
+#[test]
+fn tiny4_parse_exact() {
+    let (rest, s) = parse_tinystr4_exact(2)(b"enUS").unwrap();
+    assert_eq!(s, "en");
+    assert_eq!(rest, b"US");
+    let (rest, s) = parse_tinystr4_exact(4)(b"Latn").unwrap();
+    assert_eq!(s, "Latn");
+    assert_eq!(rest, b"");
+
+    match parse_tinystr4_exact(4)(b"en") {
+        Err(Err::Error(e)) => assert_eq!(e.code, ErrorKind::Eof),
+        other => panic!("{:?}", other),
+    }
+    match parse_tinystr4_exact(3)(b"e\0n") {
+        Err(Err::Error(e)) => assert_eq!(e.code, ErrorKind::MapRes),
+        other => panic!("{:?}", other),
+    }
+    assert!(parse_tinystr4_exact(2)(b"e\xff").is_err());
+    assert!(parse_tinystr4_exact(5)(b"abcde").is_err());
+    assert!(parse_tinystr4_exact(0)(b"abcde").is_err());
+}
+
+#[test]
+fn tiny8_parse() {
+    let (rest, s) = parse_tinystr8(b"zh-Hant-TW").unwrap();
+    assert_eq!(s, "zh-Hant-");
+    assert_eq!(rest, b"TW");
+    let (rest, s) = parse_tinystr8(b"windows\0\0").unwrap();
+    assert_eq!(s, "windows");
+    assert_eq!(rest, b"\0\0");
+    assert!(parse_tinystr8(b"").is_err());
+
+    let (rest, s) = parse_tinystr8_exact(7)(b"windows\0").unwrap();
+    assert_eq!(s, "windows");
+    assert_eq!(rest, b"\0");
+    assert!(parse_tinystr8_exact(8)(b"windows\0").is_err());
+    assert!(parse_tinystr8_exact(8)(b"windows").is_err());
+}
+
+#[test]
+fn tiny16_parse() {
+    let (rest, s) = parse_tinystr16(b"MacintoshOSX2019!").unwrap();
+    assert_eq!(s, "MacintoshOSX2019");
+    assert_eq!(rest, b"!");
+    let (rest, s) = parse_tinystr16(b"infiniband\x80").unwrap();
+    assert_eq!(s, "infiniband");
+    assert_eq!(rest, b"\x80");
+    assert!(parse_tinystr16(b"\x80").is_err());
+
+    let (rest, s) = parse_tinystr16_exact(10)(b"infinibandx").unwrap();
+    assert_eq!(s, "infiniband");
+    assert_eq!(rest, b"x");
+    assert!(parse_tinystr16_exact(17)(b"MacintoshOSX2019!").is_err());
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "rkyv")]
+
+use std::convert::TryInto;
+use std::mem::size_of;
+
+use rkyv::{check_archived_root, Archived, Deserialize, Infallible};
+use tinystr::{ArchivedTinyStrError, Error, TinyStr16, TinyStr4, TinyStr8};
+
+#[test]
+fn tiny4_rkyv() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let bytes = rkyv::to_bytes::<_, 16>(&s).unwrap();
+    assert_eq!(&bytes[..], b"en\0\0");
+    let archived = unsafe { rkyv::archived_root::<TinyStr4>(&bytes) };
+    assert_eq!(*archived, s);
+    assert_eq!(archived.get(), s);
+    let t: TinyStr4 = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(t, s);
+    assert_eq!(size_of::<Archived<TinyStr4>>(), size_of::<TinyStr4>());
+}
+
+#[test]
+fn tiny8_rkyv() {
+    let strings: Vec<TinyStr8> = ["windows", "macos", "und"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let bytes = rkyv::to_bytes::<_, 64>(&strings).unwrap();
+    let archived = unsafe { rkyv::archived_root::<Vec<TinyStr8>>(&bytes) };
+    assert_eq!(archived.len(), 3);
+    let v: Vec<TinyStr8> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(v, strings);
+    assert_eq!(size_of::<Archived<TinyStr8>>(), size_of::<TinyStr8>());
+}
+
+#[test]
+fn tiny16_rkyv() {
+    let s: TinyStr16 = "MacintoshOSX2019".parse().unwrap();
+    let bytes = rkyv::to_bytes::<_, 32>(&s).unwrap();
+    assert_eq!(&bytes[..], b"MacintoshOSX2019");
+    let archived = unsafe { rkyv::archived_root::<TinyStr16>(&bytes) };
+    let t: TinyStr16 = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(t, s);
+    assert_eq!(size_of::<Archived<TinyStr16>>(), size_of::<TinyStr16>());
+}
+
+#[test]
+fn tiny4_rkyv_checked() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    let bytes = rkyv::to_bytes::<_, 16>(&s).unwrap();
+    assert_eq!(check_archived_root::<TinyStr4>(&bytes).unwrap(), &s);
+
+    // "a\0b\0" has an interior NUL.
+    let bytes = rkyv::to_bytes::<_, 16>(&0x0062_0061u32).unwrap();
+    let err = check_archived_root::<TinyStr4>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("InvalidNull"), "{}", err);
+}
+
+#[test]
+fn tiny8_rkyv_checked_vec() {
+    let strings: Vec<TinyStr8> = ["en", "zh-Hant"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let mut bytes = rkyv::to_bytes::<_, 64>(&strings).unwrap();
+    let archived = check_archived_root::<Vec<TinyStr8>>(&bytes).unwrap();
+    let v: Vec<TinyStr8> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(v, strings);
+
+    // Corrupt the second element with a non-ASCII byte.
+    bytes[8] = 0xff;
+    assert!(check_archived_root::<Vec<TinyStr8>>(&bytes).is_err());
+    // And the first with all zeros.
+    bytes[..8].copy_from_slice(&[0; 8]);
+    assert!(check_archived_root::<Vec<TinyStr8>>(&bytes).is_err());
+}
+
+#[test]
+fn tiny16_rkyv_checked() {
+    let bytes = rkyv::to_bytes::<_, 32>(&0u128).unwrap();
+    assert!(check_archived_root::<TinyStr16>(&bytes).is_err());
+    let bytes =
+        rkyv::to_bytes::<_, 32>(&u128::from_le_bytes(*b"ab\0\0\0\0\0\0\0\0\0\0\0\0\0c")).unwrap();
+    assert!(check_archived_root::<TinyStr16>(&bytes).is_err());
+}
+
+#[test]
+fn rkyv_error() {
+    let err = ArchivedTinyStrError(Error::NonAscii { index: 1 });
+    assert_eq!(
+        err.to_string(),
+        "invalid archived TinyStr: NonAscii { index: 1 }"
+    );
+}
+
+#[test]
+fn tiny8_rkyv_raw_words() {
+    let strings: Vec<TinyStr8> = ["en", "zh-Hant", "windows_"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let bytes = rkyv::to_bytes::<_, 64>(&strings).unwrap();
+    // The elements come first, each as its little-endian word.
+    for (i, s) in strings.iter().enumerate() {
+        let raw: [u8; 8] = bytes[8 * i..8 * (i + 1)].try_into().unwrap();
+        assert_eq!(u64::from_le_bytes(raw), u64::from(*s));
+        assert_eq!(TinyStr8::from_bytes_padded(raw), Ok(*s));
+    }
+    let archived = unsafe { rkyv::archived_root::<Vec<TinyStr8>>(&bytes) };
+    for (a, s) in archived.iter().zip(&strings) {
+        let t: TinyStr8 = a.deserialize(&mut Infallible).unwrap();
+        assert_eq!(t, *s);
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use tinystr::{any_tinystr16, any_tinystr4, any_tinystr8};
+
+proptest! {
+    #[test]
+    fn tiny4_strategy(s in any_tinystr4()) {
+        prop_assert_eq!(s.as_str().parse(), Ok(s));
+        let upper = s.to_ascii_uppercase();
+        prop_assert_eq!(upper.as_str(), s.as_str().to_ascii_uppercase());
+        prop_assert_eq!(s.to_ascii_lowercase().to_ascii_uppercase(), upper);
+    }
+
+    #[test]
+    fn tiny8_strategy(s in any_tinystr8()) {
+        prop_assert_eq!(s.as_str().parse(), Ok(s));
+        let upper = s.to_ascii_uppercase();
+        prop_assert_eq!(upper.as_str(), s.as_str().to_ascii_uppercase());
+        prop_assert_eq!(s.to_ascii_lowercase().to_ascii_uppercase(), upper);
+    }
+
+    #[test]
+    fn tiny16_strategy(s in any_tinystr16()) {
+        prop_assert_eq!(s.as_str().parse(), Ok(s));
+        let upper = s.to_ascii_uppercase();
+        prop_assert_eq!(upper.as_str(), s.as_str().to_ascii_uppercase());
+        prop_assert_eq!(s.to_ascii_lowercase().to_ascii_uppercase(), upper);
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "zerocopy")]
+
+use tinystr::{
+    Error, TinyStr16, TinyStr16Unvalidated, TinyStr4, TinyStr4Unvalidated, TinyStr8,
+    TinyStr8Unvalidated,
+};
+use zerocopy::{AsBytes, LayoutVerified};
+
+#[test]
+fn tiny4_as_bytes() {
+    let s: TinyStr4 = "en".parse().unwrap();
+    assert_eq!(s.as_bytes(), b"en");
+    assert_eq!(AsBytes::as_bytes(&s), b"en\0\0");
+}
+
+#[test]
+fn tiny4_unvalidated() {
+    let buf = *b"en\0\0fr\0\0";
+    let (lv, rest) =
+        LayoutVerified::<_, TinyStr4Unvalidated>::new_unaligned_from_prefix(&buf[..]).unwrap();
+    assert_eq!(lv.into_ref().validate().unwrap(), "en");
+    let lv = LayoutVerified::<_, TinyStr4Unvalidated>::new_unaligned(rest).unwrap();
+    assert_eq!(lv.into_ref().validate().unwrap(), "fr");
+
+    let s: TinyStr4 = "zh".parse().unwrap();
+    assert_eq!(TinyStr4Unvalidated::from(s).validate(), Ok(s));
+    assert_eq!(
+        TinyStr4Unvalidated(*b"a\0b\0").validate(),
+        Err(Error::InvalidNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr4Unvalidated([0; 4]).validate(),
+        Err(Error::InvalidSize { len: 0, max: 4 })
+    );
+}
+
+#[test]
+fn tiny8_as_bytes() {
+    let s: TinyStr8 = "windows".parse().unwrap();
+    assert_eq!(AsBytes::as_bytes(&s), b"windows\0");
+}
+
+#[test]
+fn tiny8_unvalidated() {
+    let buf = *b"xwindows";
+    let lv = LayoutVerified::<_, TinyStr8Unvalidated>::new_unaligned(&buf[..]).unwrap();
+    assert_eq!(lv.into_ref().validate().unwrap(), "xwindows");
+    let buf = *b"ab\xe4\0\0\0\0\0";
+    let lv = LayoutVerified::<_, TinyStr8Unvalidated>::new_unaligned(&buf[..]).unwrap();
+    assert_eq!(lv.into_ref().validate(), Err(Error::NonAscii { index: 2 }));
+    assert!(LayoutVerified::<_, TinyStr8Unvalidated>::new_unaligned(&buf[..7]).is_none());
+}
+
+#[test]
+fn tiny16_as_bytes() {
+    let s: TinyStr16 = "metamorphosis".parse().unwrap();
+    assert_eq!(AsBytes::as_bytes(&s), b"metamorphosis\0\0\0");
+}
+
+#[test]
+fn tiny16_unvalidated() {
+    let mut buf = [0; 17];
+    buf[1..14].copy_from_slice(b"metamorphosis");
+    let lv = LayoutVerified::<_, TinyStr16Unvalidated>::new_unaligned(&buf[1..]).unwrap();
+    assert_eq!(lv.into_ref().validate().unwrap(), "metamorphosis");
+    let s: TinyStr16 = "metamorphosis".parse().unwrap();
+    assert_eq!(TinyStr16Unvalidated::from(s).as_bytes(), &buf[1..]);
+}
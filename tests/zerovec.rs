@@ -0,0 +1,50 @@
+#![cfg(feature = "zerovec")]
+
+use tinystr::{TinyStr16, TinyStr4, TinyStr8};
+use zerovec::ZeroVec;
+
+#[test]
+fn tiny4_zerovec() {
+    let strings: Vec<TinyStr4> = ["en", "fr", "Latn", "419"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let zv = ZeroVec::alloc_from_slice(&strings);
+    assert_eq!(zv.len(), 4);
+    assert_eq!(zv.get(2), Some(strings[2]));
+    assert_eq!(zv.to_vec(), strings);
+    assert_eq!(zv.as_bytes(), b"en\0\0fr\0\0Latn419\0");
+
+    let parsed: ZeroVec<TinyStr4> = ZeroVec::parse_bytes(zv.as_bytes()).unwrap();
+    assert_eq!(parsed, zv);
+    assert!(ZeroVec::<TinyStr4>::parse_bytes(b"en\0\0fr").is_err());
+    assert!(ZeroVec::<TinyStr4>::parse_bytes(b"en\0\0e\0n\0").is_err());
+    assert!(ZeroVec::<TinyStr4>::parse_bytes(b"en\0\0\0\0\0\0").is_err());
+    assert!(ZeroVec::<TinyStr4>::parse_bytes(b"en\0\0\xe9\0\0\0").is_err());
+}
+
+#[test]
+fn tiny8_zerovec() {
+    let strings: Vec<TinyStr8> = ["windows", "macos", "zh-Hant"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let zv = ZeroVec::alloc_from_slice(&strings);
+    assert_eq!(zv.to_vec(), strings);
+    let parsed: ZeroVec<TinyStr8> = ZeroVec::parse_bytes(zv.as_bytes()).unwrap();
+    assert_eq!(parsed.get(1), Some(strings[1]));
+    assert!(ZeroVec::<TinyStr8>::parse_bytes(b"windows\x80").is_err());
+}
+
+#[test]
+fn tiny16_zerovec() {
+    let strings: Vec<TinyStr16> = ["MacintoshOSX2019", "infiniband"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let zv = ZeroVec::alloc_from_slice(&strings);
+    assert_eq!(zv.to_vec(), strings);
+    let parsed: ZeroVec<TinyStr16> = ZeroVec::parse_bytes(zv.as_bytes()).unwrap();
+    assert_eq!(parsed.get(0), Some(strings[0]));
+    assert!(ZeroVec::<TinyStr16>::parse_bytes(&[0; 16]).is_err());
+}